@@ -40,7 +40,9 @@ fn main() -> Result<(), String> {
             }
             Event::JoyButtonDown{ button_idx, .. } =>{
                 println!("Button {} down", button_idx);
-                haptic.rumble_play(0.5, 500);
+                if let Err(e) = haptic.rumble_play(0.5, 500) {
+                    println!("Could not rumble: {}", e);
+                }
             },
             Event::JoyButtonUp{ button_idx, .. } =>
                 println!("Button {} up", button_idx),
@@ -84,6 +84,9 @@ impl AudioSubsystem {
     /// Opens a new audio device for capture (given the desired parameters and callback).
     /// Supported since SDL 2.0.5
     ///
+    /// The buffer given to `AudioCallback::callback` contains the recorded samples,
+    /// instead of having to be filled like for playback devices.
+    ///
     /// If you want to modify the callback-owned data at a later point (for example to update
     /// its data buffer) you're likely to be interested in the
     /// [AudioDevice.lock method](audio/struct.AudioDevice.html#method.lock).
@@ -110,6 +113,7 @@ impl AudioSubsystem {
         }
     }
 
+    /// Returns the number of audio playback devices, or `None` if SDL cannot list them.
     pub fn num_audio_playback_devices(&self) -> Option<u32> {
         let result = unsafe { sys::SDL_GetNumAudioDevices(0) };
         if result < 0 {
@@ -120,6 +124,8 @@ impl AudioSubsystem {
         }
     }
 
+    /// Returns the name of the audio playback device at `index`, which can be given to
+    /// `open_playback` or `open_queue`.
     pub fn audio_playback_device_name(&self, index: u32) -> Result<String, String> {
         unsafe {
             let dev_name = sys::SDL_GetAudioDeviceName(index as c_int, 0);
@@ -127,7 +133,32 @@ impl AudioSubsystem {
                 Err(get_error())
             } else {
                 let cstr = CStr::from_ptr(dev_name as *const _);
-                Ok(cstr.to_str().unwrap().to_owned())
+                Ok(cstr.to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Returns the number of audio capture devices, or `None` if SDL cannot list them.
+    pub fn num_audio_capture_devices(&self) -> Option<u32> {
+        let result = unsafe { sys::SDL_GetNumAudioDevices(1) };
+        if result < 0 {
+            // SDL cannot retrieve a list of audio devices. This is not necessarily an error (see the SDL2 docs).
+            None
+        } else {
+            Some(result as u32)
+        }
+    }
+
+    /// Returns the name of the audio capture device at `index`, which can be given to
+    /// `open_capture`.
+    pub fn audio_capture_device_name(&self, index: u32) -> Result<String, String> {
+        unsafe {
+            let dev_name = sys::SDL_GetAudioDeviceName(index as c_int, 1);
+            if dev_name.is_null() {
+                Err(get_error())
+            } else {
+                let cstr = CStr::from_ptr(dev_name as *const _);
+                Ok(cstr.to_string_lossy().into_owned())
             }
         }
     }
@@ -330,6 +361,10 @@ impl Drop for AudioSpecWAV {
     }
 }
 
+/// The callback of an `AudioDevice`, called from the audio thread.
+///
+/// For a playback device (`open_playback`), the callback must fill the buffer with the samples
+/// to play. For a capture device (`open_capture`), the buffer contains the recorded samples.
 pub trait AudioCallback: Send
 where Self::Channel: AudioFormatNum + 'static
 {
@@ -592,6 +627,10 @@ impl<'a, Channel: AudioFormatNum> AudioQueue<Channel> {
     #[inline]
     pub fn spec(&self) -> &AudioSpec { &self.spec }
 
+    /// Returns the id of the opened device, as reported by `Event::AudioDeviceRemoved`.
+    #[inline]
+    pub fn device_id(&self) -> u32 { self.device_id.id() }
+
     pub fn status(&self) -> AudioStatus {
         unsafe {
             let status = sys::SDL_GetAudioDeviceStatus(self.device_id.id());
@@ -615,11 +654,17 @@ impl<'a, Channel: AudioFormatNum> AudioQueue<Channel> {
         result == 0
     }
 
+    /// Returns the number of bytes of audio data still queued, not yet sent to the hardware.
+    ///
+    /// Works whether the device is paused or playing.
     pub fn size(&self) -> u32 {
         unsafe {sys::SDL_GetQueuedAudioSize(self.device_id.id())}
     }
 
     /// Clears all data from the current audio queue.
+    ///
+    /// Works whether the device is paused or playing, but audio already sent to the hardware
+    /// may still be heard; pause the device first to avoid it.
     pub fn clear(&self) {
         unsafe {sys::SDL_ClearQueuedAudio(self.device_id.id());}
     }
@@ -712,6 +757,10 @@ impl<CB: AudioCallback> AudioDevice<CB> {
     #[inline]
     pub fn spec(&self) -> &AudioSpec { &self.spec }
 
+    /// Returns the id of the opened device, as reported by `Event::AudioDeviceRemoved`.
+    #[inline]
+    pub fn device_id(&self) -> u32 { self.device_id.id() }
+
     pub fn status(&self) -> AudioStatus {
         unsafe {
             let status = sys::SDL_GetAudioDeviceStatus(self.device_id.id());
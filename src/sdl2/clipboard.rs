@@ -29,9 +29,15 @@ impl crate::VideoSubsystem {
 }
 
 impl ClipboardUtil {
+    /// Puts `text` into the clipboard. An empty string clears the clipboard text.
+    ///
+    /// Returns an error if `text` contains an interior NUL byte, instead of truncating it.
     pub fn set_clipboard_text(&self, text: &str) -> Result<(), String> {
+        let text = match CString::new(text) {
+            Ok(s) => s,
+            Err(_) => return Err("text contains an interior NUL byte".to_owned()),
+        };
         unsafe {
-            let text = CString::new(text).unwrap();
             let result = sys::SDL_SetClipboardText(text.as_ptr() as *const c_char);
 
             if result != 0 {
@@ -42,20 +48,27 @@ impl ClipboardUtil {
         }
     }
 
+    /// Gets the text from the clipboard.
+    ///
+    /// Returns an empty `String` rather than an error if the clipboard holds no text.
     pub fn clipboard_text(&self) -> Result<String, String> {
+        if !self.has_clipboard_text() {
+            return Ok(String::new());
+        }
         unsafe {
             let buf = sys::SDL_GetClipboardText();
 
             if buf.is_null() {
                 Err(get_error())
             } else {
-                let s = CStr::from_ptr(buf as *const _).to_str().unwrap().to_owned();
+                let s = CStr::from_ptr(buf as *const _).to_string_lossy().into_owned();
                 sys::SDL_free(buf as *mut c_void);
                 Ok(s)
             }
         }
     }
 
+    /// Returns `true` if the clipboard holds non-empty text.
     pub fn has_clipboard_text(&self) -> bool {
         unsafe { sys::SDL_HasClipboardText() == sys::SDL_bool::SDL_TRUE }
     }
@@ -1,5 +1,6 @@
 use std::error::Error;
 use std::fmt;
+use std::ffi::CStr;
 
 /// A given integer was so big that its representation as a C integer would be
 /// negative.
@@ -47,3 +48,96 @@ impl Error for IntegerOrSdlError {
         }
     }
 }
+
+/// Looks up `name` in the SDL library the crate is linked against. Returns 0 if
+/// it isn't there.
+///
+/// This is how functions newer than the bindings are reached; see
+/// `optional_sdl_fns!`. Only a dynamically linked SDL can be searched, so with
+/// the `static-link` feature every such function is missing.
+pub(crate) fn sdl_function_address(name: &CStr) -> usize {
+    unsafe { platform::sdl_function_address(name) }
+}
+
+/// The error returned when the linked SDL lacks `function`, which first
+/// appeared in SDL `version`.
+pub(crate) fn missing_function_error(function: &str, version: &str) -> String {
+    if cfg!(feature = "static-link") {
+        format!("{} is unavailable with statically linked SDL", function)
+    } else {
+        format!("{} is not available: it requires SDL {} or newer", function, version)
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd",
+          target_os = "macos", target_os = "ios"))]
+mod platform {
+    use libc::c_void;
+    use std::ffi::CStr;
+    use std::mem;
+    use crate::sys;
+
+    pub unsafe fn sdl_function_address(name: &CStr) -> usize {
+        // Find the library that provides a function every SDL version has, and
+        // only search that one: this can't pick up or load another copy of SDL.
+        let mut info: ::libc::Dl_info = mem::zeroed();
+        if ::libc::dladdr(sys::SDL_GetVersion as *const c_void, &mut info) == 0
+            || info.dli_fname.is_null() {
+            return 0;
+        }
+
+        let handle = ::libc::dlopen(info.dli_fname, ::libc::RTLD_LAZY | ::libc::RTLD_NOLOAD);
+        if handle.is_null() {
+            return 0;
+        }
+        let address = ::libc::dlsym(handle, name.as_ptr());
+        // SDL itself stays loaded, so the address remains valid.
+        ::libc::dlclose(handle);
+
+        address as usize
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use libc::{c_char, c_void};
+    use std::ffi::CStr;
+    use crate::sys;
+
+    const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: u32 = 0x2;
+    const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: u32 = 0x4;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetModuleHandleW(name: *const u16) -> *mut c_void;
+        // Declared with an address, as it is only used with `GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS`.
+        fn GetModuleHandleExW(flags: u32, address: *const c_void, module: *mut *mut c_void) -> i32;
+        fn GetProcAddress(module: *mut c_void, name: *const c_char) -> *mut c_void;
+    }
+
+    pub unsafe fn sdl_function_address(name: &CStr) -> usize {
+        let dll_name: Vec<u16> = "SDL2.dll\0".encode_utf16().collect();
+        let mut module = GetModuleHandleW(dll_name.as_ptr());
+
+        if module.is_null() {
+            // Not loaded under its usual name: ask which module provides SDL.
+            let flags = GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS
+                | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT;
+            if GetModuleHandleExW(flags, sys::SDL_GetVersion as *const c_void, &mut module) == 0 {
+                return 0;
+            }
+        }
+
+        GetProcAddress(module, name.as_ptr() as *const c_char) as usize
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "freebsd",
+              target_os = "macos", target_os = "ios", windows)))]
+mod platform {
+    use std::ffi::CStr;
+
+    pub unsafe fn sdl_function_address(_name: &CStr) -> usize {
+        0
+    }
+}
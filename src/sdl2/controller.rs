@@ -1,4 +1,4 @@
-use libc::c_char;
+use libc::{c_char, c_void};
 use std::error;
 use std::ffi::{CString, CStr, NulError};
 use std::fmt;
@@ -7,9 +7,9 @@ use std::path::Path;
 use crate::rwops::RWops;
 
 use crate::GameControllerSubsystem;
-use crate::get_error;
+use crate::{clear_error, get_error};
 use crate::joystick;
-use crate::common::{validate_int, IntegerOrSdlError};
+use crate::common::{missing_function_error, validate_int, IntegerOrSdlError};
 use std::mem::transmute;
 
 use crate::sys;
@@ -102,6 +102,40 @@ impl GameControllerSubsystem {
         }
     }
 
+    /// Return the mapping of the controller at index `joystick_index`, without opening it.
+    ///
+    /// This is the `which` of `Event::ControllerDeviceAdded`.
+    pub fn mapping_for_index(&self, joystick_index: u32) -> Result<String, IntegerOrSdlError> {
+        use crate::common::IntegerOrSdlError::*;
+        let joystick_index = validate_int(joystick_index, "joystick_index")?;
+        let c_str = unsafe { sys::SDL_GameControllerMappingForDeviceIndex(joystick_index) };
+
+        if c_str.is_null() {
+            Err(SdlError(get_error()))
+        } else {
+            let mapping = c_str_to_string(c_str);
+            unsafe { sys::SDL_free(c_str as *mut c_void) };
+            Ok(mapping)
+        }
+    }
+
+    /// Return the instance id the controller at index `joystick_index` has once opened,
+    /// without opening it.
+    ///
+    /// Events other than `Event::ControllerDeviceAdded` identify the controller with this id,
+    /// e.g. `Event::ControllerDeviceRemoved`.
+    pub fn instance_id_for_index(&self, joystick_index: u32) -> Result<u32, IntegerOrSdlError> {
+        use crate::common::IntegerOrSdlError::*;
+        let joystick_index = validate_int(joystick_index, "joystick_index")?;
+        let result = unsafe { sys::SDL_JoystickGetDeviceInstanceID(joystick_index) };
+
+        if result < 0 {
+            Err(SdlError(get_error()))
+        } else {
+            Ok(result as u32)
+        }
+    }
+
     /// If state is `true` controller events are processed, otherwise
     /// they're ignored.
     pub fn set_event_state(&self, state: bool) {
@@ -202,11 +236,17 @@ impl Axis {
 
     /// Return a string for a given axis in the same format using by
     /// the game controller mapping strings
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use sdl2::controller::Axis;
+    ///
+    /// assert_eq!(Axis::TriggerLeft.string(), "lefttrigger");
+    /// assert_eq!(Axis::from_string("lefttrigger"), Some(Axis::TriggerLeft));
+    /// ```
     pub fn string(self) -> String {
-        let axis: sys::SDL_GameControllerAxis;
-        unsafe { axis = transmute(self); }
-
-        let string = unsafe { sys::SDL_GameControllerGetStringForAxis(axis) };
+        let string = unsafe { sys::SDL_GameControllerGetStringForAxis(self.to_ll()) };
 
         c_str_to_string(string)
     }
@@ -271,11 +311,17 @@ impl Button {
 
     /// Return a string for a given button in the same format using by
     /// the game controller mapping strings
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use sdl2::controller::Button;
+    ///
+    /// assert_eq!(Button::DPadUp.string(), "dpup");
+    /// assert_eq!(Button::from_string("dpup"), Some(Button::DPadUp));
+    /// ```
     pub fn string(self) -> String {
-        let button: sys::SDL_GameControllerButton;
-        unsafe { button = transmute(self); }
-
-        let string = unsafe { sys::SDL_GameControllerGetStringForButton(button) };
+        let string = unsafe { sys::SDL_GameControllerGetStringForButton(self.to_ll()) };
 
         c_str_to_string(string)
     }
@@ -330,6 +376,84 @@ pub enum MappingStatus {
     Updated = 0,
 }
 
+/// The kind of controller, as far as SDL can tell from its USB ids.
+///
+/// This is mostly useful to show the right button glyphs, e.g. "Cross"
+/// instead of "A" on PlayStation controllers. Types added in SDL releases
+/// newer than this enum are reported as `Unknown`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(i32)]
+pub enum ControllerType {
+    Unknown = 0,
+    Xbox360 = 1,
+    XboxOne = 2,
+    PS3 = 3,
+    PS4 = 4,
+    NintendoSwitchPro = 5,
+    Virtual = 6,
+    PS5 = 7,
+    AmazonLuna = 8,
+    GoogleStadia = 9,
+}
+
+impl ControllerType {
+    /// Converts an `SDL_GameControllerType` value; unknown values become `Unknown`.
+    pub fn from_ll(raw: i32) -> ControllerType {
+        match raw {
+            1 => ControllerType::Xbox360,
+            2 => ControllerType::XboxOne,
+            3 => ControllerType::PS3,
+            4 => ControllerType::PS4,
+            5 => ControllerType::NintendoSwitchPro,
+            6 => ControllerType::Virtual,
+            7 => ControllerType::PS5,
+            8 => ControllerType::AmazonLuna,
+            9 => ControllerType::GoogleStadia,
+            _ => ControllerType::Unknown,
+        }
+    }
+
+    pub fn to_ll(self) -> i32 {
+        self as i32
+    }
+}
+
+/// The state of a finger on a controller touchpad, as returned by
+/// `GameController::touchpad_finger`.
+///
+/// `x` and `y` are normalized between `0.0` and `1.0`, and so is `pressure`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TouchpadFinger {
+    /// Whether the finger is currently touching the touchpad.
+    pub down: bool,
+    pub x: f32,
+    pub y: f32,
+    pub pressure: f32,
+}
+
+mod ll {
+    use libc::c_int;
+    use crate::sys;
+
+    // Not in the SDL 2.0.10 bindings; looked up at runtime. `SDL_GameControllerType` is
+    // returned as a plain integer since newer SDL versions keep adding values to it.
+    optional_sdl_fns! {
+        pub fn SDL_GameControllerGetType(gamecontroller: *mut sys::SDL_GameController) -> c_int;
+        pub fn SDL_GameControllerSetPlayerIndex(gamecontroller: *mut sys::SDL_GameController,
+                                                player_index: c_int) -> ();
+        pub fn SDL_GameControllerGetNumTouchpads(gamecontroller: *mut sys::SDL_GameController) -> c_int;
+        pub fn SDL_GameControllerGetNumTouchpadFingers(gamecontroller: *mut sys::SDL_GameController,
+                                                       touchpad: c_int) -> c_int;
+        pub fn SDL_GameControllerGetTouchpadFinger(gamecontroller: *mut sys::SDL_GameController,
+                                                   touchpad: c_int, finger: c_int,
+                                                   state: *mut u8, x: *mut f32, y: *mut f32,
+                                                   pressure: *mut f32) -> c_int;
+        pub fn SDL_GameControllerHasLED(gamecontroller: *mut sys::SDL_GameController) -> sys::SDL_bool;
+        pub fn SDL_GameControllerSetLED(gamecontroller: *mut sys::SDL_GameController,
+                                        red: u8, green: u8, blue: u8) -> c_int;
+    }
+}
+
 /// Wrapper around the `SDL_GameController` object
 pub struct GameController {
     subsystem: GameControllerSubsystem,
@@ -377,6 +501,137 @@ impl GameController {
         }
     }
 
+    /// Returns the kind of this controller.
+    ///
+    /// Always returns `ControllerType::Unknown` if the linked SDL is older than 2.0.12 or
+    /// statically linked.
+    pub fn controller_type(&self) -> ControllerType {
+        match unsafe { ll::SDL_GameControllerGetType(self.raw) } {
+            Some(raw) => ControllerType::from_ll(raw),
+            None => ControllerType::Unknown,
+        }
+    }
+
+    /// Returns the player index of this controller, or `None` if it has none.
+    ///
+    /// For XInput controllers this is the XInput user index.
+    pub fn player_index(&self) -> Option<i32> {
+        let index = unsafe { sys::SDL_GameControllerGetPlayerIndex(self.raw) };
+
+        if index < 0 { None } else { Some(index) }
+    }
+
+    /// Sets the player index of this controller, lighting the matching
+    /// player LED on controllers that have them. `None` clears it.
+    ///
+    /// Returns an error if the linked SDL is older than 2.0.12 or statically linked.
+    pub fn set_player_index(&mut self, index: Option<i32>) -> Result<(), String> {
+        let index = match index {
+            Some(index) if index >= 0 => index,
+            _ => -1,
+        };
+
+        match unsafe { ll::SDL_GameControllerSetPlayerIndex(self.raw, index) } {
+            Some(()) => Ok(()),
+            None => Err(missing_function_error("SDL_GameControllerSetPlayerIndex", "2.0.12")),
+        }
+    }
+
+    /// Returns the number of touchpads on this controller, such as the one of
+    /// a DualShock 4 or DualSense.
+    ///
+    /// Always returns 0 if the linked SDL is older than 2.0.14 or statically linked.
+    pub fn num_touchpads(&self) -> u32 {
+        match unsafe { ll::SDL_GameControllerGetNumTouchpads(self.raw) } {
+            Some(result) if result > 0 => result as u32,
+            _ => 0,
+        }
+    }
+
+    /// Returns how many fingers `touchpad` can track at the same time, or 0
+    /// if there is no such touchpad.
+    ///
+    /// Always returns 0 if the linked SDL is older than 2.0.14 or statically linked.
+    pub fn num_touchpad_fingers(&self, touchpad: u32) -> u32 {
+        let touchpad = match validate_int(touchpad, "touchpad") {
+            Ok(touchpad) => touchpad,
+            Err(_) => return 0,
+        };
+        match unsafe { ll::SDL_GameControllerGetNumTouchpadFingers(self.raw, touchpad) } {
+            Some(result) if result > 0 => result as u32,
+            _ => 0,
+        }
+    }
+
+    /// Returns the state of finger slot `finger` on `touchpad`, or `None` if
+    /// either doesn't exist.
+    ///
+    /// A slot keeps its last position after the finger is lifted; check
+    /// `TouchpadFinger::down`.
+    ///
+    /// Always returns `None` if the linked SDL is older than 2.0.14 or statically linked.
+    pub fn touchpad_finger(&self, touchpad: u32, finger: u32) -> Option<TouchpadFinger> {
+        let touchpad = validate_int(touchpad, "touchpad").ok()?;
+        let finger = validate_int(finger, "finger").ok()?;
+        let (mut state, mut x, mut y, mut pressure) = (0, 0.0, 0.0, 0.0);
+
+        let result = unsafe {
+            ll::SDL_GameControllerGetTouchpadFinger(self.raw, touchpad, finger,
+                                                    &mut state, &mut x, &mut y, &mut pressure)
+        };
+
+        match result {
+            Some(0) => Some(TouchpadFinger { down: state != 0, x, y, pressure }),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this controller has an LED whose color can be set
+    /// with `set_led`.
+    ///
+    /// Always returns `false` if the linked SDL is older than 2.0.14 or statically linked.
+    pub fn has_led(&self) -> bool {
+        unsafe { ll::SDL_GameControllerHasLED(self.raw) == Some(sys::SDL_bool::SDL_TRUE) }
+    }
+
+    /// Sets the color of the controller's LED, such as the light bar of a
+    /// DualShock 4 or DualSense.
+    ///
+    /// Errors if the controller has no modifiable LED or if the linked SDL is
+    /// older than 2.0.14 or statically linked.
+    pub fn set_led(&mut self, red: u8, green: u8, blue: u8) -> Result<(), String> {
+        match unsafe { ll::SDL_GameControllerSetLED(self.raw, red, green, blue) } {
+            Some(0) => Ok(()),
+            Some(_) => Err(get_error()),
+            None => Err(missing_function_error("SDL_GameControllerSetLED", "2.0.14")),
+        }
+    }
+
+    /// Retrieve the battery level of the joystick backing this controller
+    pub fn power_level(&self) -> Result<joystick::PowerLevel, IntegerOrSdlError> {
+        use crate::common::IntegerOrSdlError::*;
+        clear_error();
+
+        let result = unsafe {
+            let joystick = sys::SDL_GameControllerGetJoystick(self.raw);
+            sys::SDL_JoystickCurrentPowerLevel(joystick)
+        };
+
+        let state = joystick::PowerLevel::from_ll(result);
+
+        if result != sys::SDL_JoystickPowerLevel::SDL_JOYSTICK_POWER_UNKNOWN {
+            Ok(state)
+        } else {
+            let err = get_error();
+
+            if err.is_empty() {
+                Ok(state)
+            } else {
+                Err(SdlError(err))
+            }
+        }
+    }
+
     /// Get the position of the given `axis`
     pub fn axis(&self, axis: Axis) -> i16 {
         // This interface is a bit messed up: 0 is a valid position
@@ -414,6 +669,11 @@ impl GameController {
     /// the rumble effect to keep playing for a long time, as this results in
     /// the effect ending immediately after starting due to an overflow.
     /// Use some smaller, "huge enough" number instead.
+    ///
+    /// Each call replaces the current rumble effect instead of adding to it;
+    /// use intensities of 0 to stop it early.
+    ///
+    /// Returns an `SdlError` if the controller has no rumble motors.
     pub fn set_rumble(&mut self,
                       low_frequency_rumble: u16,
                       high_frequency_rumble: u16,
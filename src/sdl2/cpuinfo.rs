@@ -1,3 +1,7 @@
+use std::ptr;
+use std::slice;
+
+use crate::get_error;
 use crate::sys;
 use crate::sys::SDL_bool;
 
@@ -51,6 +55,86 @@ pub fn has_avx() -> bool {
     unsafe { sys::SDL_HasAVX() == SDL_bool::SDL_TRUE }
 }
 
+pub fn has_avx2() -> bool {
+    unsafe { sys::SDL_HasAVX2() == SDL_bool::SDL_TRUE }
+}
+
+pub fn has_avx512f() -> bool {
+    unsafe { sys::SDL_HasAVX512F() == SDL_bool::SDL_TRUE }
+}
+
+pub fn has_neon() -> bool {
+    unsafe { sys::SDL_HasNEON() == SDL_bool::SDL_TRUE }
+}
+
 pub fn system_ram() -> i32 {
     unsafe { sys::SDL_GetSystemRAM() }
 }
+
+/// The alignment, in bytes, that SIMD code needs on this CPU; memory from
+/// `SimdBuffer` is always aligned to this.
+pub fn simd_alignment() -> usize {
+    unsafe { sys::SDL_SIMDGetAlignment() as usize }
+}
+
+/// A zero-initialized byte buffer allocated with `SDL_SIMDAlloc`, aligned to
+/// `simd_alignment()` and freed with `SDL_SIMDFree` when dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use sdl2::cpuinfo::{simd_alignment, SimdBuffer};
+///
+/// let mut buffer = SimdBuffer::new(1024).unwrap();
+/// assert_eq!(buffer.as_ptr() as usize % simd_alignment(), 0);
+/// buffer.as_mut_slice()[0] = 42;
+/// ```
+pub struct SimdBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl SimdBuffer {
+    /// Allocates a buffer of `len` bytes, all set to zero.
+    ///
+    /// Errors if SDL fails to allocate the memory.
+    pub fn new(len: usize) -> Result<SimdBuffer, String> {
+        let ptr = unsafe { sys::SDL_SIMDAlloc(len as sys::size_t) } as *mut u8;
+        if ptr.is_null() {
+            return Err(get_error());
+        }
+        unsafe { ptr::write_bytes(ptr, 0, len) };
+        Ok(SimdBuffer { ptr, len })
+    }
+
+    /// The size of the buffer in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl Drop for SimdBuffer {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_SIMDFree(self.ptr as *mut _) }
+    }
+}
@@ -10,7 +10,7 @@ use std::borrow::ToOwned;
 use std::iter::FromIterator;
 use std::convert::TryFrom;
 use std::marker::PhantomData;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Mutex;
 use std::mem::transmute;
 use libc::c_void;
@@ -25,6 +25,7 @@ use crate::keyboard::Keycode;
 use crate::mouse;
 use crate::mouse::{MouseButton, MouseState, MouseWheelDirection};
 use crate::keyboard::Scancode;
+use crate::video::Orientation;
 use crate::get_error;
 
 use crate::sys;
@@ -32,20 +33,26 @@ use crate::sys::SDL_EventType;
 
 struct CustomEventTypeMaps {
     sdl_id_to_type_id: HashMap<u32, ::std::any::TypeId>,
-    type_id_to_sdl_id: HashMap<::std::any::TypeId, u32>
+    type_id_to_sdl_id: HashMap<::std::any::TypeId, u32>,
+    /// Payloads pushed by `push_custom_event` that haven't been reclaimed yet.
+    pending_payloads: HashSet<usize>
 }
 
 impl CustomEventTypeMaps {
     fn new() -> Self {
         CustomEventTypeMaps {
             sdl_id_to_type_id: HashMap::new(),
-            type_id_to_sdl_id: HashMap::new()
+            type_id_to_sdl_id: HashMap::new(),
+            pending_payloads: HashSet::new()
         }
     }
 }
 
 lazy_static! {
     static ref CUSTOM_EVENT_TYPES : Mutex<CustomEventTypeMaps> = { Mutex::new(CustomEventTypeMaps::new()) };
+    /// Held while the event filter is set or unset, so that an `EventFilter` being dropped
+    /// can't unset a filter that another thread has just set.
+    static ref EVENT_FILTER_LOCK : Mutex<()> = { Mutex::new(()) };
 }
 
 impl crate::EventSubsystem {
@@ -55,6 +62,25 @@ impl crate::EventSubsystem {
     }
 
     /// Removes all events in the event queue that match the specified type range.
+    ///
+    /// The bounds are inclusive and can be taken from `EventType`, e.g.
+    /// `EventType::KeyDown as u32`.
+    ///
+    /// Only events already in the queue are removed. To also discard input
+    /// the operating system hasn't delivered yet, pump events first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::event::EventType;
+    ///
+    /// let sdl_context = sdl2::init().unwrap();
+    /// let event_subsystem = sdl_context.event().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    ///
+    /// // ... after a long loading screen, drop buffered keyboard and mouse input
+    /// event_pump.pump_events();
+    /// event_subsystem.flush_events(EventType::KeyDown as u32, EventType::MouseWheel as u32);
+    /// ```
     pub fn flush_events(&self, min_type: u32, max_type: u32) {
         unsafe { sys::SDL_FlushEvents(min_type, max_type) };
     }
@@ -226,6 +252,58 @@ impl crate::EventSubsystem {
         self.event_sender().push_custom_event(event)
     }
 
+    /// Sets the event filter, replacing the current one.
+    ///
+    /// The filter is called for every event before it is added to the queue, and the event
+    /// is dropped if it returns `false`. Events already in the queue are removed by SDL
+    /// when the filter is set.
+    ///
+    /// The filter runs on the thread pushing the event, which is not necessarily the main
+    /// thread, so it should be quick and must be `Send`. SDL never calls it from two
+    /// threads at once.
+    ///
+    /// The filter is unset when the returned `EventFilter` is dropped. It must be `'static`
+    /// since the `EventFilter` may be leaked, e.g. with `mem::forget`, in which case SDL keeps
+    /// calling it until it is replaced.
+    #[must_use = "if unused the EventFilter will be dropped immediately"]
+    pub fn set_event_filter<F>(&self, filter: F) -> EventFilter
+            where F: FnMut(&Event) -> bool + Send + 'static {
+        let mut callback: Box<EventFilterCallback> = Box::new(Box::new(filter));
+        let userdata = &mut *callback as *mut EventFilterCallback as *mut c_void;
+        {
+            let _lock = EVENT_FILTER_LOCK.lock().unwrap();
+            unsafe { sys::SDL_SetEventFilter(Some(event_filter_trampoline), userdata) };
+        }
+
+        EventFilter {
+            _subsystem: self.clone(),
+            callback,
+        }
+    }
+
+    /// Adds a callback that is called for every event added to the queue.
+    ///
+    /// Unlike an event filter, a watch can't drop events, and several watches can be added.
+    /// It is called on the thread pushing the event, which is not necessarily the main
+    /// thread, so it should be quick and must be `Send`. SDL never calls it from two
+    /// threads at once.
+    ///
+    /// The watch is removed when the returned `EventWatch` is dropped. It must be `'static`
+    /// since the `EventWatch` may be leaked, e.g. with `mem::forget`, in which case SDL keeps
+    /// calling it.
+    #[must_use = "if unused the EventWatch will be dropped immediately"]
+    pub fn add_event_watch<F>(&self, watch: F) -> EventWatch
+            where F: FnMut(&Event) + Send + 'static {
+        let mut callback: Box<EventWatchCallback> = Box::new(Box::new(watch));
+        let userdata = &mut *callback as *mut EventWatchCallback as *mut c_void;
+        unsafe { sys::SDL_AddEventWatch(Some(event_watch_trampoline), userdata) };
+
+        EventWatch {
+            _subsystem: self.clone(),
+            callback,
+        }
+    }
+
     /// Create an event sender that can be sent to other threads.
     ///
     /// An `EventSender` will not keep the event subsystem alive. If the event subsystem is
@@ -251,6 +329,7 @@ pub enum EventType {
     AppWillEnterForeground = SDL_EventType::SDL_APP_WILLENTERFOREGROUND as u32,
     AppDidEnterForeground = SDL_EventType::SDL_APP_DIDENTERFOREGROUND as u32,
 
+    Display = SDL_EventType::SDL_DISPLAYEVENT as u32,
     Window = SDL_EventType::SDL_WINDOWEVENT as u32,
     // TODO: SysWM = sys::SDL_SYSWMEVENT as u32,
 
@@ -297,6 +376,8 @@ pub enum EventType {
     AudioDeviceAdded = SDL_EventType::SDL_AUDIODEVICEADDED as u32,
     AudioDeviceRemoved = SDL_EventType::SDL_AUDIODEVICEREMOVED as u32,
 
+    SensorUpdate = SDL_EventType::SDL_SENSORUPDATE as u32,
+
     RenderTargetsReset = SDL_EventType::SDL_RENDER_TARGETS_RESET as u32,
     RenderDeviceReset = SDL_EventType::SDL_RENDER_DEVICE_RESET as u32,
 
@@ -322,6 +403,7 @@ impl TryFrom<u32> for EventType {
             SDL_APP_WILLENTERFOREGROUND => AppWillEnterForeground,
             SDL_APP_DIDENTERFOREGROUND => AppDidEnterForeground,
 
+            SDL_DISPLAYEVENT => Display,
             SDL_WINDOWEVENT => Window,
 
             SDL_KEYDOWN => KeyDown,
@@ -365,6 +447,8 @@ impl TryFrom<u32> for EventType {
             SDL_AUDIODEVICEADDED => AudioDeviceAdded,
             SDL_AUDIODEVICEREMOVED => AudioDeviceRemoved,
 
+            SDL_SENSORUPDATE => SensorUpdate,
+
             SDL_RENDER_TARGETS_RESET => RenderTargetsReset,
             SDL_RENDER_DEVICE_RESET => RenderDeviceReset,
 
@@ -376,6 +460,38 @@ impl TryFrom<u32> for EventType {
     }
 }
 
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+/// An enum of display events.
+pub enum DisplayEvent {
+    None,
+    /// The display orientation has changed.
+    OrientationChanged(Orientation),
+    /// A display has been added to the system. Requires SDL 2.0.14.
+    Connected,
+    /// A display has been removed from the system. Requires SDL 2.0.14.
+    Disconnected,
+}
+
+impl DisplayEvent {
+    fn from_ll(id: u8, data1: i32) -> DisplayEvent {
+        match id {
+            1 => DisplayEvent::OrientationChanged(Orientation::from_ll(data1)),
+            2 => DisplayEvent::Connected,
+            3 => DisplayEvent::Disconnected,
+            _ => DisplayEvent::None,
+        }
+    }
+
+    fn to_ll(&self) -> (u8, i32) {
+        match *self {
+            DisplayEvent::None => (0, 0),
+            DisplayEvent::OrientationChanged(orientation) => (1, orientation.to_ll()),
+            DisplayEvent::Connected => (2, 0),
+            DisplayEvent::Disconnected => (3, 0),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 /// An enum of window events.
 pub enum WindowEvent {
@@ -458,6 +574,13 @@ pub enum Event {
     AppWillEnterForeground { timestamp: u32 },
     AppDidEnterForeground { timestamp: u32 },
 
+    /// A display was connected, disconnected or has changed orientation.
+    Display {
+        timestamp: u32,
+        /// The index of the display, as used by `VideoSubsystem`.
+        display_index: i32,
+        display_event: DisplayEvent,
+    },
     Window {
         timestamp: u32,
         window_id: u32,
@@ -526,13 +649,26 @@ pub enum Event {
         y: i32
     },
 
+    /// The mouse wheel was scrolled.
+    ///
+    /// When `direction` is `MouseWheelDirection::Flipped`, SDL reports the amounts with the
+    /// opposite sign; `Event::normalized_wheel_amounts` and
+    /// `Event::normalized_precise_wheel_amounts` undo this.
     MouseWheel {
         timestamp: u32,
         window_id: u32,
         which: u32,
+        /// The amount scrolled horizontally, positive to the right.
         x: i32,
+        /// The amount scrolled vertically, positive away from the user.
         y: i32,
         direction: MouseWheelDirection,
+        /// The amount scrolled horizontally, with fractional precision on high-resolution
+        /// devices. Before SDL 2.0.18 this is always equal to `x`.
+        precise_x: f32,
+        /// The amount scrolled vertically, with fractional precision on high-resolution
+        /// devices. Before SDL 2.0.18 this is always equal to `y`.
+        precise_y: f32,
     },
 
     JoyAxisMotion {
@@ -575,12 +711,14 @@ pub enum Event {
 
     JoyDeviceAdded {
         timestamp: u32,
-        /// The newly added joystick's `joystick_index`
+        /// The newly added joystick's `joystick_index`, to be given to
+        /// `JoystickSubsystem::open`. This is *not* its `id`.
         which: u32
     },
     JoyDeviceRemoved {
         timestamp: u32,
-        /// The joystick's `id`
+        /// The joystick's `id`, as returned by `Joystick::instance_id`.
+        /// This is *not* a `joystick_index`.
         which: u32
     },
 
@@ -607,12 +745,15 @@ pub enum Event {
 
     ControllerDeviceAdded {
         timestamp: u32,
-        /// The newly added controller's `joystick_index`
+        /// The newly added controller's `joystick_index`, to be given to
+        /// `GameControllerSubsystem::open`. This is *not* its `id`, which can be obtained with
+        /// `GameControllerSubsystem::instance_id_for_index`.
         which: u32
     },
     ControllerDeviceRemoved {
         timestamp: u32,
-        /// The controller's joystick `id`
+        /// The controller's joystick `id`, as returned by `GameController::instance_id`.
+        /// This is *not* a `joystick_index`.
         which: u32
     },
     ControllerDeviceRemapped {
@@ -652,6 +793,10 @@ pub enum Event {
         pressure: f32
     },
 
+    /// A gesture matching a template was drawn; see the `gesture` module.
+    /// `gesture_id` is the closest template and `error` how far the gesture
+    /// was from it, lower being closer. `x` and `y` are the normalized center
+    /// of the gesture.
     DollarGesture {
         timestamp: u32,
         touch_id: i64,
@@ -661,6 +806,8 @@ pub enum Event {
         x: f32,
         y: f32
     },
+    /// A gesture template was recorded after a call to
+    /// `gesture::record_gesture`; `gesture_id` identifies the new template.
     DollarRecord {
         timestamp: u32,
         touch_id: i64,
@@ -671,6 +818,9 @@ pub enum Event {
         y: f32
     },
 
+    /// Several fingers moved together. `d_theta` is the rotation and `d_dist`
+    /// the change in spread (pinch) since the last event, and `x` and `y` the
+    /// normalized center of the fingers.
     MultiGesture {
         timestamp: u32,
         touch_id: i64,
@@ -685,16 +835,23 @@ pub enum Event {
         timestamp: u32
     },
 
+    /// A file was dropped onto a window. The path is copied, and the buffer allocated by SDL
+    /// is freed when the event is polled.
     DropFile {
         timestamp: u32,
         window_id: u32,
         filename: String
     },
+    /// Text was dropped onto a window. The text is copied, and the buffer allocated by SDL is
+    /// freed when the event is polled.
     DropText {
         timestamp: u32,
         window_id: u32,
+        /// The dropped text.
         filename: String
     },
+    /// One or more files or texts are about to be dropped onto a window, followed by
+    /// `DropFile` or `DropText` events and a `DropComplete` event.
     DropBegin {
         timestamp: u32,
         window_id: u32,
@@ -704,17 +861,35 @@ pub enum Event {
         window_id: u32,
     },
 
+    /// A new audio device is available, e.g. because a headset was plugged in.
     AudioDeviceAdded {
         timestamp: u32,
+        /// The index of the new device, as used by `AudioSubsystem::audio_playback_device_name`
+        /// or `AudioSubsystem::audio_capture_device_name`.
         which: u32,
+        /// Whether the device is a capture (recording) device.
         iscapture: bool,
     },
+    /// An opened audio device was removed. The device is paused and should be closed; open
+    /// another one, such as the new default device, to keep playing.
     AudioDeviceRemoved {
         timestamp: u32,
+        /// The id of the removed device, as returned by `AudioDevice::device_id` and
+        /// `AudioQueue::device_id`; this is not a device index.
         which: u32,
+        /// Whether the device is a capture (recording) device.
         iscapture: bool,
     },
 
+    /// A sensor opened with `SensorSubsystem::open` has new data.
+    SensorUpdate {
+        timestamp: u32,
+        /// The instance id of the sensor.
+        which: u32,
+        /// The first six values of the sensor, use `Sensor::get_data` to read more.
+        data: [f32; 6],
+    },
+
     RenderTargetsReset {
         timestamp: u32,
     },
@@ -802,6 +977,28 @@ impl Event {
                 }
             },
 
+            Event::Display{
+                timestamp,
+                display_index,
+                display_event
+            } => {
+                let (display_event_id, data1) = display_event.to_ll();
+                let event = sys::SDL_DisplayEvent {
+                    type_: SDL_EventType::SDL_DISPLAYEVENT as u32,
+                    timestamp,
+                    display: display_index as u32,
+                    event: display_event_id,
+                    padding1: 0,
+                    padding2: 0,
+                    padding3: 0,
+                    data1,
+                };
+                unsafe {
+                    ptr::copy(&event, ret.as_mut_ptr() as *mut sys::SDL_DisplayEvent, 1);
+                    Some(ret.assume_init())
+                }
+            },
+
             Event::Window{
                 timestamp,
                 window_id,
@@ -961,18 +1158,24 @@ impl Event {
                 x,
                 y,
                 direction,
+                precise_x,
+                precise_y,
             } => {
-                let event = sys::SDL_MouseWheelEvent {
-                    type_: SDL_EventType::SDL_MOUSEWHEEL as u32,
-                    timestamp,
-                    windowID: window_id,
-                    which,
-                    x,
-                    y,
-                    direction : direction.to_ll(),
+                let event = MouseWheelEventExt {
+                    base: sys::SDL_MouseWheelEvent {
+                        type_: SDL_EventType::SDL_MOUSEWHEEL as u32,
+                        timestamp,
+                        windowID: window_id,
+                        which,
+                        x,
+                        y,
+                        direction : direction.to_ll(),
+                    },
+                    precise_x,
+                    precise_y,
                 };
                 unsafe {
-                    ptr::copy(&event, ret.as_mut_ptr() as *mut sys::SDL_MouseWheelEvent, 1);
+                    ptr::copy(&event, ret.as_mut_ptr() as *mut MouseWheelEventExt, 1);
                     Some(ret.assume_init())
                 }
             },
@@ -1251,6 +1454,39 @@ impl Event {
         }
     }
 
+    /// Like `from_ll`, but leaves the memory SDL attached to the event alone, for events that
+    /// are still owned by SDL (such as the ones given to event filters).
+    fn from_ll_borrowed(raw: &sys::SDL_Event) -> Event {
+        let raw_type = unsafe { raw.type_ };
+
+        if raw_type == SDL_EventType::SDL_DROPFILE as u32
+            || raw_type == SDL_EventType::SDL_DROPTEXT as u32 {
+            let event = unsafe { raw.drop };
+            let filename = if event.file.is_null() {
+                String::new()
+            } else {
+                let buf = unsafe { CStr::from_ptr(event.file as *const _).to_bytes() };
+                String::from_utf8_lossy(buf).into_owned()
+            };
+
+            return if raw_type == SDL_EventType::SDL_DROPFILE as u32 {
+                Event::DropFile {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    filename
+                }
+            } else {
+                Event::DropText {
+                    timestamp: event.timestamp,
+                    window_id: event.windowID,
+                    filename
+                }
+            };
+        }
+
+        Event::from_ll(*raw)
+    }
+
     fn from_ll(raw: sys::SDL_Event) -> Event {
         let raw_type = unsafe { raw.type_ };
 
@@ -1286,6 +1522,16 @@ impl Event {
                 Event::AppDidEnterForeground { timestamp: event.timestamp }
             }
 
+            EventType::Display => {
+                let event = raw.display;
+
+                Event::Display {
+                    timestamp: event.timestamp,
+                    display_index: event.display as i32,
+                    display_event: DisplayEvent::from_ll(event.event, event.data1),
+                }
+            }
+
             EventType::Window => {
                 let event = raw.window;
 
@@ -1397,6 +1643,13 @@ impl Event {
             EventType::MouseWheel => {
                 let event = raw.wheel;
 
+                let (precise_x, precise_y) = if has_precise_wheel_amounts() {
+                    let ext = *(&raw as *const sys::SDL_Event as *const MouseWheelEventExt);
+                    (ext.precise_x, ext.precise_y)
+                } else {
+                    (event.x as f32, event.y as f32)
+                };
+
                 Event::MouseWheel {
                     timestamp: event.timestamp,
                     window_id: event.windowID,
@@ -1404,6 +1657,8 @@ impl Event {
                     x: event.x,
                     y: event.y,
                     direction: mouse::MouseWheelDirection::from_ll(event.direction),
+                    precise_x,
+                    precise_y,
                 }
             }
 
@@ -1603,10 +1858,7 @@ impl Event {
             }
             EventType::DropFile => {
                 let event = raw.drop;
-
-                let buf = CStr::from_ptr(event.file as *const _).to_bytes();
-                let text = String::from_utf8_lossy(buf).to_string();
-                sys::SDL_free(event.file as *mut c_void);
+                let text = take_drop_text(event.file);
 
                 Event::DropFile {
                     timestamp: event.timestamp,
@@ -1616,10 +1868,7 @@ impl Event {
             },
             EventType::DropText => {
                 let event = raw.drop;
-
-                let buf = CStr::from_ptr(event.file as *const _).to_bytes();
-                let text = String::from_utf8_lossy(buf).to_string();
-                sys::SDL_free(event.file as *mut c_void);
+                let text = take_drop_text(event.file);
 
                 Event::DropText {
                     timestamp: event.timestamp,
@@ -1662,6 +1911,15 @@ impl Event {
                 }
             },
 
+            EventType::SensorUpdate => {
+                let event = raw.sensor;
+                Event::SensorUpdate {
+                    timestamp: event.timestamp,
+                    which: event.which as u32,
+                    data: event.data,
+                }
+            },
+
             EventType::RenderTargetsReset => {
                 Event::RenderTargetsReset {
                     timestamp: raw.common.timestamp
@@ -1719,6 +1977,35 @@ impl Event {
         }
     }
 
+    /// Returns the `x` and `y` amounts of a `MouseWheel` event, with their sign flipped back
+    /// if `direction` is `MouseWheelDirection::Flipped`, so that positive `x` is always to the
+    /// right and positive `y` away from the user.
+    ///
+    /// Returns `None` if this isn't a `MouseWheel` event.
+    pub fn normalized_wheel_amounts(&self) -> Option<(i32, i32)> {
+        match *self {
+            Event::MouseWheel { x, y, direction: MouseWheelDirection::Flipped, .. } => Some((-x, -y)),
+            Event::MouseWheel { x, y, .. } => Some((x, y)),
+            _ => None
+        }
+    }
+
+    /// Like `normalized_wheel_amounts`, but for `precise_x` and `precise_y`.
+    pub fn normalized_precise_wheel_amounts(&self) -> Option<(f32, f32)> {
+        match *self {
+            Event::MouseWheel { precise_x, precise_y, direction: MouseWheelDirection::Flipped, .. } => {
+                Some((-precise_x, -precise_y))
+            }
+            Event::MouseWheel { precise_x, precise_y, .. } => Some((precise_x, precise_y)),
+            _ => None
+        }
+    }
+
+    /// Takes the payload of a custom event pushed with `push_custom_event`.
+    ///
+    /// Returns `None` if this isn't a custom event of type `T`, or if the payload has already
+    /// been taken, for instance through a clone of this event. The payload of a custom event
+    /// that is never taken is leaked.
     pub fn as_user_event_type<T: ::std::any::Any>(&self) -> Option<T> {
         use ::std::any::TypeId;
         let type_id = TypeId::of::<Box<T>>();
@@ -1728,14 +2015,13 @@ impl Event {
             _ => { return None }
         };
 
-        let cet = CUSTOM_EVENT_TYPES.lock().unwrap();
+        let mut cet = CUSTOM_EVENT_TYPES.lock().unwrap();
 
-        let event_type_id = match cet.sdl_id_to_type_id.get(&event_id) {
-            Some(id) => id,
-            None => { panic!("internal error; could not find typeid") }
-        };
+        if cet.sdl_id_to_type_id.get(&event_id) != Some(&type_id) {
+            return None;
+        }
 
-        if &type_id != event_type_id {
+        if !cet.pending_payloads.remove(&(event_box_ptr as usize)) {
             return None;
         }
 
@@ -1746,6 +2032,7 @@ impl Event {
 }
 
 unsafe fn poll_event() -> Option<Event> {
+    crate::video::release_deferred_windows();
     let mut raw = mem::MaybeUninit::uninit();
     let has_pending = sys::SDL_PollEvent(raw.as_mut_ptr()) == 1;
 
@@ -1754,6 +2041,7 @@ unsafe fn poll_event() -> Option<Event> {
 }
 
 unsafe fn wait_event() -> Event {
+    crate::video::release_deferred_windows();
     let mut raw = mem::MaybeUninit::uninit();
     let success = sys::SDL_WaitEvent(raw.as_mut_ptr()) == 1;
 
@@ -1762,8 +2050,11 @@ unsafe fn wait_event() -> Event {
 }
 
 unsafe fn wait_event_timeout(timeout: u32) -> Option<Event> {
+    crate::video::release_deferred_windows();
     let mut raw = mem::MaybeUninit::uninit();
-    let success = sys::SDL_WaitEventTimeout(raw.as_mut_ptr(), timeout as c_int) == 1;
+    // SDL waits forever for negative timeouts.
+    let timeout = ::std::cmp::min(timeout, c_int::max_value() as u32) as c_int;
+    let success = sys::SDL_WaitEventTimeout(raw.as_mut_ptr(), timeout) == 1;
 
     if success { Some(Event::from_ll(raw.assume_init())) }
     else { None }
@@ -1785,6 +2076,29 @@ impl crate::EventPump {
     }
 
     /// Disable an event type. Returns if the event type was enabled before the call.
+    ///
+    /// Events of a disabled type are dropped as soon as they are generated, so
+    /// they never reach the queue; this includes events sent with
+    /// `EventSubsystem::push_event`. This is useful to avoid queue churn from
+    /// events an application never looks at, such as finger or gesture events.
+    ///
+    /// Disabling an event only suppresses its delivery. SDL still processes
+    /// the underlying input internally: for example, the keyboard and mouse
+    /// states keep updating when their events are disabled, and disabling
+    /// `TextInput` does not stop text input mode or hide an on-screen
+    /// keyboard; use `TextInputUtil::stop` for that.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::event::EventType;
+    ///
+    /// let sdl_context = sdl2::init().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    ///
+    /// for &event_type in &[EventType::FingerDown, EventType::FingerUp, EventType::FingerMotion] {
+    ///     event_pump.disable_event(event_type);
+    /// }
+    /// ```
     pub fn disable_event(&mut self, event_type: EventType) -> bool {
         let result = unsafe { sys::SDL_EventState(event_type as u32, sys::SDL_DISABLE as c_int) };
 
@@ -1821,16 +2135,51 @@ impl crate::EventPump {
     }
 
     /// Pumps the event loop, gathering events from the input devices.
+    ///
+    /// The gathered events are left in the queue; they can then be inspected
+    /// with `has_event`, peeked, or consumed with `poll_event` and friends.
+    /// Polling and waiting already pump the event loop themselves.
     pub fn pump_events(&mut self) {
+        crate::video::release_deferred_windows();
         unsafe { sys::SDL_PumpEvents(); };
     }
 
+    /// Checks whether an event of the given type is in the event queue,
+    /// without removing it.
+    ///
+    /// This does not pump the event loop; call `pump_events` first to see
+    /// events that haven't been gathered yet.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl2::event::EventType;
+    ///
+    /// let sdl_context = sdl2::init().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    ///
+    /// event_pump.pump_events();
+    /// if event_pump.has_event(EventType::Quit) {
+    ///     println!("quit requested");
+    /// }
+    /// ```
+    pub fn has_event(&self, event_type: EventType) -> bool {
+        unsafe { sys::SDL_HasEvent(event_type as u32) == sys::SDL_bool::SDL_TRUE }
+    }
+
+    /// Checks whether any event with a type in the inclusive range
+    /// `min_type..=max_type` is in the event queue, without removing it.
+    pub fn has_events(&self, min_type: u32, max_type: u32) -> bool {
+        unsafe { sys::SDL_HasEvents(min_type, max_type) == sys::SDL_bool::SDL_TRUE }
+    }
+
     /// Waits indefinitely for the next available event.
     pub fn wait_event(&mut self) -> Event {
         unsafe { wait_event() }
     }
 
     /// Waits until the specified timeout (in milliseconds) for the next available event.
+    ///
+    /// Returns `None` if no event arrived before the timeout.
     pub fn wait_event_timeout(&mut self, timeout: u32) -> Option<Event> {
         unsafe { wait_event_timeout(timeout) }
     }
@@ -1909,7 +2258,8 @@ impl<'a> Iterator for EventWaitTimeoutIterator<'a> {
 #[cfg(test)]
 mod test {
     use super::Event;
-    use super::WindowEvent;
+    use super::{DisplayEvent, WindowEvent};
+    use super::super::video::Orientation;
     use super::super::controller::{Button, Axis};
     use super::super::joystick::{HatState};
     use super::super::mouse::{MouseButton, MouseState, MouseWheelDirection};
@@ -1924,6 +2274,15 @@ mod test {
             let e2 = Event::from_ll(e.clone().to_ll().unwrap());
             assert_eq!(e, e2);
         }
+        {
+            let e = Event::Display{
+                timestamp: 0,
+                display_index: 1,
+                display_event: DisplayEvent::OrientationChanged(Orientation::Portrait),
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
         {
             let e = Event::Window{
                 timestamp: 0,
@@ -2007,6 +2366,8 @@ mod test {
                 x: 23,
                 y: 91,
                 direction: MouseWheelDirection::Flipped,
+                precise_x: 23.0,
+                precise_y: 91.0,
             };
             let e2 = Event::from_ll(e.clone().to_ll().unwrap());
             assert_eq!(e, e2);
@@ -2130,6 +2491,38 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_normalized_wheel_amounts() {
+        let flipped = Event::MouseWheel {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            x: 3,
+            y: -2,
+            direction: MouseWheelDirection::Flipped,
+            precise_x: 3.5,
+            precise_y: -2.25,
+        };
+        assert_eq!(flipped.normalized_wheel_amounts(), Some((-3, 2)));
+        assert_eq!(flipped.normalized_precise_wheel_amounts(), Some((-3.5, 2.25)));
+
+        let normal = Event::MouseWheel {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            x: 3,
+            y: -2,
+            direction: MouseWheelDirection::Normal,
+            precise_x: 3.5,
+            precise_y: -2.25,
+        };
+        assert_eq!(normal.normalized_wheel_amounts(), Some((3, -2)));
+        assert_eq!(normal.normalized_precise_wheel_amounts(), Some((3.5, -2.25)));
+
+        let other = Event::Quit { timestamp: 0 };
+        assert_eq!(other.normalized_wheel_amounts(), None);
+    }
+
     #[test]
     fn test_from_ll_keymod_keydown_unknown_bits() {
         let mut raw_event = Event::KeyDown {
@@ -2226,7 +2619,7 @@ impl EventSender {
             -> Result<(), String> {
 
         use ::std::any::TypeId;
-        let cet = CUSTOM_EVENT_TYPES.lock().unwrap();
+        let mut cet = CUSTOM_EVENT_TYPES.lock().unwrap();
         let type_id = TypeId::of::<Box<T>>();
 
         let user_event_id = *match cet.type_id_to_sdl_id.get(&type_id) {
@@ -2238,18 +2631,111 @@ impl EventSender {
             }
         };
 
-        let event_box = Box::new(event);
+        let event_box_ptr = Box::into_raw(Box::new(event));
         let event = Event::User {
            timestamp: 0,
            window_id: 0,
            type_: user_event_id,
            code: 0,
-           data1: Box::into_raw(event_box) as *mut c_void,
+           data1: event_box_ptr as *mut c_void,
            data2: ::std::ptr::null_mut()
         };
 
-        self.push_event(event)?;
+        cet.pending_payloads.insert(event_box_ptr as usize);
+        // Event filters and watches may reclaim the payload while the event is pushed.
+        drop(cet);
+
+        if let Err(e) = self.push_event(event) {
+            let mut cet = CUSTOM_EVENT_TYPES.lock().unwrap();
+            // The event never reached the queue, unless a filter dropped it after
+            // reclaiming the payload.
+            if cet.pending_payloads.remove(&(event_box_ptr as usize)) {
+                drop(unsafe { Box::from_raw(event_box_ptr) });
+            }
+            return Err(e);
+        }
 
         Ok(())
     }
 }
+
+type EventFilterCallback = Box<dyn FnMut(&Event) -> bool + Send>;
+type EventWatchCallback = Box<dyn FnMut(&Event) + Send>;
+
+/// An event filter set with `EventSubsystem::set_event_filter`.
+///
+/// The filter is unset when this is dropped, unless it has been replaced since.
+pub struct EventFilter {
+    _subsystem: crate::EventSubsystem,
+    callback: Box<EventFilterCallback>,
+}
+
+impl Drop for EventFilter {
+    fn drop(&mut self) {
+        let userdata = &mut *self.callback as *mut EventFilterCallback as *mut c_void;
+        let _lock = EVENT_FILTER_LOCK.lock().unwrap();
+        unsafe {
+            let mut current_filter = None;
+            let mut current_userdata = ptr::null_mut();
+            sys::SDL_GetEventFilter(&mut current_filter, &mut current_userdata);
+            // Only unset the filter if it hasn't been replaced since.
+            if current_userdata == userdata {
+                sys::SDL_SetEventFilter(None, ptr::null_mut());
+            }
+        }
+    }
+}
+
+/// An event watch added with `EventSubsystem::add_event_watch`.
+///
+/// The watch is removed when this is dropped.
+pub struct EventWatch {
+    _subsystem: crate::EventSubsystem,
+    callback: Box<EventWatchCallback>,
+}
+
+impl Drop for EventWatch {
+    fn drop(&mut self) {
+        let userdata = &mut *self.callback as *mut EventWatchCallback as *mut c_void;
+        unsafe { sys::SDL_DelEventWatch(Some(event_watch_trampoline), userdata) };
+    }
+}
+
+// SDL holds its event watcher lock while calling filters and watches, and while they are
+// removed, so the callbacks are never used after the guards above are dropped.
+unsafe extern "C" fn event_filter_trampoline(userdata: *mut c_void, event: *mut sys::SDL_Event) -> c_int {
+    let callback = &mut *(userdata as *mut EventFilterCallback);
+    callback(&Event::from_ll_borrowed(&*event)) as c_int
+}
+
+unsafe extern "C" fn event_watch_trampoline(userdata: *mut c_void, event: *mut sys::SDL_Event) -> c_int {
+    let callback = &mut *(userdata as *mut EventWatchCallback);
+    callback(&Event::from_ll_borrowed(&*event));
+    0
+}
+
+/// Copies the text attached to a drop event and frees the buffer allocated by SDL.
+unsafe fn take_drop_text(file: *mut libc::c_char) -> String {
+    if file.is_null() {
+        return String::new();
+    }
+
+    let text = String::from_utf8_lossy(CStr::from_ptr(file as *const _).to_bytes()).into_owned();
+    sys::SDL_free(file as *mut c_void);
+    text
+}
+
+/// `SDL_MouseWheelEvent` as of SDL 2.0.18, which appended the precise scroll amounts.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct MouseWheelEventExt {
+    base: sys::SDL_MouseWheelEvent,
+    precise_x: f32,
+    precise_y: f32,
+}
+
+/// Whether the linked SDL fills in the fields of `MouseWheelEventExt`.
+fn has_precise_wheel_amounts() -> bool {
+    let version = crate::version::version();
+    (version.major, version.minor, version.patch) >= (2, 0, 18)
+}
@@ -7,19 +7,21 @@ use libc::c_char;
 
 use crate::sys;
 
-pub fn base_path() -> Result<String, String> {
-    let result = unsafe {
-        let buf = sys::SDL_GetBasePath();
-        let s = CStr::from_ptr(buf as *const _).to_str().unwrap().to_owned();
-        sys::SDL_free(buf as *mut c_void);
-        s
-    };
-
-    if result.is_empty() {
-        Err(get_error())
-    } else {
-        Ok(result)
+/// Copies an SDL-allocated path into a `String` and frees it, or returns `None` if `buf`
+/// is null.
+unsafe fn take_sdl_path(buf: *mut c_char) -> Option<String> {
+    if buf.is_null() {
+        return None;
     }
+    let s = CStr::from_ptr(buf as *const _).to_string_lossy().into_owned();
+    sys::SDL_free(buf as *mut c_void);
+    Some(s)
+}
+
+/// Return the directory the application was run from, with a trailing path separator.
+pub fn base_path() -> Result<String, String> {
+    let result = unsafe { take_sdl_path(sys::SDL_GetBasePath()) };
+    result.filter(|path| !path.is_empty()).ok_or_else(get_error)
 }
 
 #[derive(Debug)]
@@ -56,25 +58,24 @@ impl error::Error for PrefPathError {
 // TODO: Change to OsStr or something?
 /// Return the preferred directory for the application to write files on this
 /// system, based on the given organization and application name.
+///
+/// The directory is created if it doesn't exist yet, and the returned path ends with a
+/// path separator.
 pub fn pref_path(org_name: &str, app_name: &str)
         -> Result<String, PrefPathError> {
     use self::PrefPathError::*;
+    let org = match CString::new(org_name) {
+        Ok(s) => s,
+        Err(err) => return Err(InvalidOrganizationName(err)),
+    };
+    let app = match CString::new(app_name) {
+        Ok(s) =>s,
+        Err(err) => return Err(InvalidApplicationName(err)),
+    };
     let result = unsafe {
-        let org = match CString::new(org_name) {
-            Ok(s) => s,
-            Err(err) => return Err(InvalidOrganizationName(err)),
-        };
-        let app = match CString::new(app_name) {
-            Ok(s) =>s,
-            Err(err) => return Err(InvalidApplicationName(err)),
-        };
         let buf = sys::SDL_GetPrefPath(org.as_ptr() as *const c_char, app.as_ptr() as *const c_char);
-        CStr::from_ptr(buf as *const _).to_str().unwrap().to_owned()
+        take_sdl_path(buf)
     };
 
-    if result.is_empty() {
-        Err(SdlError(get_error()))
-    } else {
-        Ok(result)
-    }
+    result.filter(|path| !path.is_empty()).ok_or_else(|| SdlError(get_error()))
 }
@@ -1,2 +1,69 @@
-#![allow(unused)]
+//! Recording and recognition of $1 ("dollar") gestures.
+//!
+//! Call `record_gesture` and draw a shape with one finger: SDL sends an
+//! `Event::DollarRecord` with the id of the new template. Afterwards,
+//! whenever a similar shape is drawn, an `Event::DollarGesture` is sent with
+//! the id of the closest template. Templates can be saved to and loaded from
+//! an `RWops`, so they only have to be recorded once.
+//!
+//! Multi-finger pinch and rotate gestures are reported as
+//! `Event::MultiGesture` and need no setup.
+//!
+//! Gestures are only processed while the event subsystem is initialized.
+use crate::get_error;
+use crate::rwops::RWops;
 use crate::sys;
+use crate::touch::TouchDevice;
+
+/// Identifies a recorded dollar gesture template.
+pub type GestureId = sys::SDL_GestureID;
+
+/// Can be passed instead of a specific device to `record_gesture` and
+/// `load_dollar_templates` to use every touch device.
+pub const ALL_TOUCH_DEVICES: TouchDevice = -1;
+
+/// Starts recording a new gesture template on `touch`, or on any touch
+/// device if `ALL_TOUCH_DEVICES` is given.
+///
+/// The next gesture drawn is recorded and reported with an
+/// `Event::DollarRecord`. Returns `false` if the touch device wasn't found.
+pub fn record_gesture(touch: TouchDevice) -> bool {
+    unsafe { sys::SDL_RecordGesture(touch) == 1 }
+}
+
+/// Writes every currently loaded template to `dst`, returning how many were
+/// written.
+pub fn save_all_dollar_templates(dst: &mut RWops) -> u32 {
+    unsafe { sys::SDL_SaveAllDollarTemplates(dst.raw()) as u32 }
+}
+
+/// Writes the template with the given id to `dst`.
+///
+/// Errors if there is no such template or if writing fails.
+pub fn save_dollar_template(gesture_id: GestureId, dst: &mut RWops) -> Result<(), String> {
+    let ret = unsafe { sys::SDL_SaveDollarTemplate(gesture_id, dst.raw()) };
+
+    if ret <= 0 { Err(get_error()) } else { Ok(()) }
+}
+
+/// Loads templates previously written by `save_all_dollar_templates` or
+/// `save_dollar_template` for `touch`, or for every touch device if
+/// `ALL_TOUCH_DEVICES` is given. Returns how many templates were loaded.
+///
+/// Errors if the touch device wasn't found.
+///
+/// # Example
+///
+/// ```no_run
+/// use sdl2::gesture;
+/// use sdl2::rwops::RWops;
+///
+/// let mut file = RWops::from_file("gestures.bin", "rb").unwrap();
+/// let loaded = gesture::load_dollar_templates(gesture::ALL_TOUCH_DEVICES, &mut file).unwrap();
+/// println!("loaded {} gesture templates", loaded);
+/// ```
+pub fn load_dollar_templates(touch: TouchDevice, src: &mut RWops) -> Result<u32, String> {
+    let ret = unsafe { sys::SDL_LoadDollarTemplates(touch, src.raw()) };
+
+    if ret < 0 { Err(get_error()) } else { Ok(ret as u32) }
+}
@@ -7,22 +7,30 @@ use crate::get_error;
 
 impl HapticSubsystem {
     /// Attempt to open the joystick at index `joystick_index` and return its haptic device.
+    ///
+    /// The joystick subsystem must be initialized, as the joystick is opened to access its
+    /// haptic device. It stays open until the `Haptic` is dropped.
     pub fn open_from_joystick_id(&self, joystick_index: u32) -> Result<Haptic, IntegerOrSdlError> {
         use crate::common::IntegerOrSdlError::*;
         let joystick_index = validate_int(joystick_index, "joystick_index")?;
 
-        let haptic = unsafe {
-            let joystick = sys::SDL_JoystickOpen(joystick_index);
-            sys::SDL_HapticOpenFromJoystick(joystick)
-        };
+        let joystick = unsafe { sys::SDL_JoystickOpen(joystick_index) };
+        if joystick.is_null() {
+            return Err(SdlError(get_error()));
+        }
+
+        let haptic = unsafe { sys::SDL_HapticOpenFromJoystick(joystick) };
 
         if haptic.is_null() {
-            Err(SdlError(get_error()))
+            let err = get_error();
+            unsafe { sys::SDL_JoystickClose(joystick) };
+            Err(SdlError(err))
         } else {
-            unsafe { sys::SDL_HapticRumbleInit(haptic) };
             Ok(Haptic {
                 subsystem: self.clone(),
                 raw: haptic,
+                joystick,
+                rumble_initialized: false,
             })
         }
     }
@@ -32,6 +40,8 @@ impl HapticSubsystem {
 pub struct Haptic {
     subsystem: HapticSubsystem,
     raw: *mut sys::SDL_Haptic,
+    joystick: *mut sys::SDL_Joystick,
+    rumble_initialized: bool,
 }
 
 
@@ -39,20 +49,54 @@ impl Haptic {
     #[inline]
     pub fn subsystem(&self) -> &HapticSubsystem { &self.subsystem }
 
-    /// Run a simple rumble effect on the haptic device.
-    pub fn rumble_play(&mut self, strength: f32, duration: u32) {
-        unsafe { sys::SDL_HapticRumblePlay(self.raw, strength, duration) };
+    /// Returns `true` if the haptic device supports the simple rumble effect.
+    pub fn rumble_supported(&self) -> bool {
+        unsafe { sys::SDL_HapticRumbleSupported(self.raw) == 1 }
+    }
+
+    /// Run a simple rumble effect on the haptic device, with a `strength` between `0.0` and
+    /// `1.0`, for `duration` milliseconds.
+    ///
+    /// The rumble effect is initialized on the first call. This returns an error if the
+    /// device doesn't support it.
+    pub fn rumble_play(&mut self, strength: f32, duration: u32) -> Result<(), String> {
+        if !self.rumble_initialized {
+            if unsafe { sys::SDL_HapticRumbleInit(self.raw) } != 0 {
+                return Err(get_error());
+            }
+            self.rumble_initialized = true;
+        }
+
+        let result = unsafe { sys::SDL_HapticRumblePlay(self.raw, strength, duration) };
+        if result != 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
     }
 
     /// Stop the simple rumble on the haptic device.
-    pub fn rumble_stop(&mut self) {
-        unsafe { sys::SDL_HapticRumbleStop(self.raw) };
+    pub fn rumble_stop(&mut self) -> Result<(), String> {
+        if !self.rumble_initialized {
+            return Ok(());
+        }
+
+        let result = unsafe { sys::SDL_HapticRumbleStop(self.raw) };
+        if result != 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
     }
 }
 
 
 impl Drop for Haptic {
     fn drop(&mut self) {
-        unsafe { sys::SDL_HapticClose(self.raw) }
+        unsafe {
+            // The haptic device must be closed before its joystick.
+            sys::SDL_HapticClose(self.raw);
+            sys::SDL_JoystickClose(self.joystick);
+        }
     }
 }
@@ -73,18 +73,32 @@ pub fn get_video_minimize_on_focus_loss() -> bool {
     }
 }
 
+/// Sets the hint `name` to `value`, with normal priority.
+///
+/// Returns `false` if the hint wasn't set, because it has already been set with a higher
+/// priority or because `name` or `value` contains a NUL byte.
+///
+/// # Example
+/// ```rust,no_run
+/// // Use nearest-neighbor scaling for the renderers created afterwards.
+/// sdl2::hint::set("SDL_RENDER_SCALE_QUALITY", "0");
+/// ```
 pub fn set(name: &str, value: &str) -> bool{
-    let name = CString::new(name).unwrap();
-    let value = CString::new(value).unwrap();
+    let (name, value) = match (CString::new(name), CString::new(value)) {
+        (Ok(name), Ok(value)) => (name, value),
+        _ => return false,
+    };
     unsafe {
         sys::SDL_SetHint(name.as_ptr() as *const c_char, value.as_ptr() as *const c_char) == sys::SDL_bool::SDL_TRUE
     }
 }
 
+/// Returns the value of the hint `name`, or `None` if it isn't set.
 pub fn get(name: &str) -> Option<String> {
-    use std::str;
-
-    let name = CString::new(name).unwrap();
+    let name = match CString::new(name) {
+        Ok(name) => name,
+        Err(_) => return None,
+    };
 
     unsafe {
         let res = sys::SDL_GetHint(name.as_ptr() as *const c_char);
@@ -92,14 +106,23 @@ pub fn get(name: &str) -> Option<String> {
         if res.is_null() {
             None
         } else {
-            Some(str::from_utf8(CStr::from_ptr(res as *const _).to_bytes()).unwrap().to_owned())
+            Some(String::from_utf8_lossy(CStr::from_ptr(res as *const _).to_bytes()).into_owned())
         }
     }
 }
 
+/// Sets the hint `name` to `value` with the given priority.
+///
+/// Hints replace existing hints of their priority and lower. Environment variables are
+/// considered to have override priority.
+///
+/// Returns `false` if the hint wasn't set, because it has already been set with a higher
+/// priority or because `name` or `value` contains a NUL byte.
 pub fn set_with_priority(name: &str, value: &str, priority: &Hint) -> bool {
-    let name = CString::new(name).unwrap();
-    let value = CString::new(value).unwrap();
+    let (name, value) = match (CString::new(name), CString::new(value)) {
+        (Ok(name), Ok(value)) => (name, value),
+        _ => return false,
+    };
 
     let priority_val = match *priority {
         Hint::Normal => sys::SDL_HintPriority::SDL_HINT_NORMAL,
@@ -293,6 +293,9 @@ impl Joystick {
 
     /// Return a pair `(dx, dy)` containing the difference in axis
     /// position since the last poll
+    ///
+    /// The motion is accumulated by SDL between calls, so each call reports
+    /// the movement since the previous one. Errors if `ball` is out of range.
     pub fn ball(&self, ball: u32) -> Result<(i32, i32), IntegerOrSdlError> {
         use crate::common::IntegerOrSdlError::*;
         let mut dx = 0;
@@ -308,7 +311,7 @@ impl Joystick {
         }
     }
 
-    /// Retrieve the number of balls for this joystick
+    /// Retrieve the number of hats for this joystick
     pub fn num_hats(&self) -> u32 {
         let result = unsafe { sys::SDL_JoystickNumHats(self.raw) };
 
@@ -321,6 +324,9 @@ impl Joystick {
     }
 
     /// Return the position of `hat` for this joystick
+    ///
+    /// Many flight sticks report their POV switch as a hat. Errors if `hat`
+    /// is out of range.
     pub fn hat(&self, hat: u32) -> Result<HatState, IntegerOrSdlError> {
         use crate::common::IntegerOrSdlError::*;
         // Guess what? This function as well uses 0 to report an error
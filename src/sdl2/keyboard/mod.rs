@@ -3,7 +3,6 @@ use crate::rect::Rect;
 use crate::video::Window;
 
 use std::fmt;
-use std::mem::transmute;
 
 use crate::sys;
 
@@ -78,6 +77,9 @@ impl<'a> KeyboardState<'a> {
 
     /// Returns an iterator of pressed scancodes.
     ///
+    /// The iterator borrows this snapshot of the key state and does not
+    /// allocate.
+    ///
     /// # Example
     /// ```no_run
     /// use sdl2::keyboard::Keycode;
@@ -115,20 +117,20 @@ impl<'a> Iterator for ScancodeIterator<'a> {
     type Item = (Scancode, bool);
 
     fn next(&mut self) -> Option<(Scancode, bool)> {
-        if self.index < self.keyboard_state.len() as i32 {
+        // Skip over the indices that don't correspond to a known scancode
+        // with a loop rather than recursion, as there are long gaps.
+        while self.index < self.keyboard_state.len() as i32 {
             let index = self.index;
             self.index += 1;
 
             if let Some(scancode) = Scancode::from_i32(index) {
                 let pressed = self.keyboard_state[index as usize] != 0;
 
-                Some((scancode, pressed))
-            } else {
-                self.next()
+                return Some((scancode, pressed));
             }
-        } else {
-            None
         }
+
+        None
     }
 }
 
@@ -177,6 +179,14 @@ pub struct KeyboardUtil {
     _sdldrop: ::std::rc::Rc<crate::SdlDrop>
 }
 
+// `sys::SDL_Keymod` is a Rust enum, so it can't hold combinations of modifiers.
+mod ll {
+    extern "C" {
+        pub fn SDL_GetModState() -> u32;
+        pub fn SDL_SetModState(modstate: u32);
+    }
+}
+
 impl KeyboardUtil {
     /// Gets the id of the window which currently has keyboard focus.
     pub fn focused_window_id(&self) -> Option<u32> {
@@ -189,12 +199,17 @@ impl KeyboardUtil {
         }
     }
 
+    /// Returns the modifier keys that are currently held down, and the state
+    /// of the lock keys.
     pub fn mod_state(&self) -> Mod {
-        unsafe { Mod::from_bits(sys::SDL_GetModState() as u16).unwrap() }
+        unsafe { Mod::from_bits_truncate(ll::SDL_GetModState() as u16) }
     }
 
+    /// Overrides the current modifier key state.
+    ///
+    /// This doesn't change the state of the actual keyboard keys.
     pub fn set_mod_state(&self, flags: Mod) {
-        unsafe { sys::SDL_SetModState(transmute::<u32, sys::SDL_Keymod>(flags.bits() as u32)); }
+        unsafe { ll::SDL_SetModState(flags.bits() as u32); }
     }
 }
 
@@ -214,18 +229,27 @@ pub struct TextInputUtil {
 }
 
 impl TextInputUtil {
+    /// Starts accepting text input: `TextInput` and `TextEditing` events are sent,
+    /// and the on-screen keyboard or IME is shown if the platform has one.
     pub fn start(&self) {
         unsafe { sys::SDL_StartTextInput(); }
     }
 
+    /// Returns whether text input events are enabled.
     pub fn is_active(&self, ) -> bool {
         unsafe { sys::SDL_IsTextInputActive() == sys::SDL_bool::SDL_TRUE }
     }
 
+    /// Stops accepting text input, hiding the on-screen keyboard or IME.
     pub fn stop(&self) {
         unsafe { sys::SDL_StopTextInput(); }
     }
 
+    /// Sets the rectangle of the text being edited, used as a hint to position the IME
+    /// candidate list.
+    ///
+    /// The coordinates are in the space of the window that has keyboard focus, in
+    /// window coordinates (not pixels on high-DPI displays).
     pub fn set_rect(&self, rect: Rect) {
         unsafe { sys::SDL_SetTextInputRect(rect.raw() as *mut sys::SDL_Rect); }
     }
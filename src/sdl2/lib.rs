@@ -74,6 +74,7 @@ pub mod touch;
 pub mod joystick;
 pub mod controller;
 pub mod haptic;
+pub mod sensor;
 pub mod keyboard;
 pub mod mouse;
 pub mod rect;
@@ -42,6 +42,23 @@ impl Category {
             Category::Custom
         }
     }
+
+    /// `Custom` and `Unknown` map to `SDL_LOG_CATEGORY_CUSTOM`.
+    fn to_ll(self) -> libc::c_int {
+        let category = match self {
+            Category::Application => sys::SDL_LOG_CATEGORY_APPLICATION,
+            Category::Error => sys::SDL_LOG_CATEGORY_ERROR,
+            Category::Assert => sys::SDL_LOG_CATEGORY_ASSERT,
+            Category::System => sys::SDL_LOG_CATEGORY_SYSTEM,
+            Category::Audio => sys::SDL_LOG_CATEGORY_AUDIO,
+            Category::Video => sys::SDL_LOG_CATEGORY_VIDEO,
+            Category::Render => sys::SDL_LOG_CATEGORY_RENDER,
+            Category::Input => sys::SDL_LOG_CATEGORY_INPUT,
+            Category::Test => sys::SDL_LOG_CATEGORY_TEST,
+            Category::Custom | Category::Unknown => sys::SDL_LOG_CATEGORY_CUSTOM,
+        };
+        category as libc::c_int
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -66,6 +83,18 @@ impl Priority {
             SDL_LOG_PRIORITY_CRITICAL | _ => Priority::Critical,
         }
     }
+
+    fn to_ll(self) -> sys::SDL_LogPriority {
+        use crate::sys::SDL_LogPriority::*;
+        match self {
+            Priority::Verbose => SDL_LOG_PRIORITY_VERBOSE,
+            Priority::Debug => SDL_LOG_PRIORITY_DEBUG,
+            Priority::Info => SDL_LOG_PRIORITY_INFO,
+            Priority::Warn => SDL_LOG_PRIORITY_WARN,
+            Priority::Error => SDL_LOG_PRIORITY_ERROR,
+            Priority::Critical => SDL_LOG_PRIORITY_CRITICAL,
+        }
+    }
 }
 
 fn dummy(_priority: Priority, _category: Category, _message: &str) {
@@ -92,12 +121,52 @@ pub fn set_output_function(callback : fn(Priority, Category, &str)) {
     };
 }
 
+/// Sets the minimum priority of the messages logged in `category`.
+///
+/// ```rust,no_run
+/// use sdl2::log::{Category, Priority};
+///
+/// // Log which renderer backend SDL picks.
+/// sdl2::log::set_priority(Category::Render, Priority::Verbose);
+/// ```
+pub fn set_priority(category: Category, priority: Priority) {
+    unsafe { sys::SDL_LogSetPriority(category.to_ll(), priority.to_ll()) };
+}
+
+/// Returns the minimum priority of the messages logged in `category`.
+pub fn get_priority(category: Category) -> Priority {
+    Priority::from_ll(unsafe { sys::SDL_LogGetPriority(category.to_ll()) })
+}
+
+/// Resets the priorities of all categories to their defaults.
+pub fn reset_priorities() {
+    unsafe { sys::SDL_LogResetPriorities() };
+}
+
+/// Logs `message` in `category` with the given priority.
+///
+/// Like in C, the message is cut at the first NUL byte.
+pub fn message(category: Category, priority: Priority, message: &str) {
+    let message = to_log_cstring(message);
+    unsafe {
+        sys::SDL_LogMessage(category.to_ll(), priority.to_ll(),
+                            b"%s\0".as_ptr() as *const libc::c_char, message.as_ptr());
+    }
+}
+
 /// Standard log function which takes as priority INFO and
 /// as category APPLICATION
 pub fn log(message: &str) {
-    let message = message.replace('%', "%%");
-    let message = CString::new(message).unwrap();
+    let message = to_log_cstring(message);
     unsafe {
-        crate::sys::SDL_Log(message.into_raw());
+        crate::sys::SDL_Log(b"%s\0".as_ptr() as *const libc::c_char, message.as_ptr());
     }
 }
+
+fn to_log_cstring(message: &str) -> CString {
+    let message = match message.find('\0') {
+        Some(nul) => &message[..nul],
+        None => message,
+    };
+    CString::new(message).unwrap()
+}
@@ -21,3 +21,32 @@ macro_rules! impl_raw_constructor(
         )+
     )
 );
+
+// Declares SDL functions that are newer than the SDL version the `sdl2-sys`
+// bindings are generated from. Each one is looked up in the linked SDL library
+// the first time it's called, so the crate still links against older versions;
+// the wrapper returns `None` instead of calling it when it's missing. Functions
+// without a return value must be declared with `-> ()`.
+macro_rules! optional_sdl_fns(
+    ($(pub fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;)+) => (
+        $(
+        #[allow(non_snake_case)]
+        pub unsafe fn $name($($arg: $ty),*) -> Option<$ret> {
+            lazy_static! {
+                static ref ADDRESS: usize = crate::common::sdl_function_address(
+                    ::std::ffi::CStr::from_bytes_with_nul(concat!(stringify!($name), "\0").as_bytes())
+                        .unwrap()
+                );
+            }
+
+            if *ADDRESS == 0 {
+                None
+            } else {
+                let function: unsafe extern "C" fn($($ty),*) -> $ret =
+                    ::std::mem::transmute(*ADDRESS);
+                Some(function($($arg),*))
+            }
+        }
+        )+
+    )
+);
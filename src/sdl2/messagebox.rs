@@ -1,11 +1,16 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::error;
 use std::ffi::{CString, NulError};
 use std::fmt;
 use std::ptr;
+use std::sync::mpsc;
+use std::thread;
 use std::os::raw::{c_char,c_int};
 
 use crate::video::Window;
 use crate::get_error;
+use crate::EventSubsystem;
 
 use crate::sys;
 
@@ -17,6 +22,14 @@ bitflags! {
             sys::SDL_MessageBoxFlags::SDL_MESSAGEBOX_WARNING as u32;
         const INFORMATION =
             sys::SDL_MessageBoxFlags::SDL_MESSAGEBOX_INFORMATION as u32;
+        /// Buttons placed left to right (`SDL_MESSAGEBOX_BUTTONS_LEFT_TO_RIGHT`).
+        ///
+        /// Requires SDL 2.0.12; older versions ignore this flag and use their default order.
+        const BUTTONS_LEFT_TO_RIGHT = 0x0000_0080;
+        /// Buttons placed right to left (`SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT`).
+        ///
+        /// Requires SDL 2.0.12; older versions ignore this flag and use their default order.
+        const BUTTONS_RIGHT_TO_LEFT = 0x0000_0100;
     }
 }
 
@@ -30,7 +43,7 @@ bitflags! {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MessageBoxColorScheme {
     pub background:(u8,u8,u8),
     pub text:(u8,u8,u8),
@@ -39,6 +52,98 @@ pub struct MessageBoxColorScheme {
     pub button_selected:(u8,u8,u8)
 }
 
+impl MessageBoxColorScheme {
+    /// Returns a builder starting from SDL's default color scheme.
+    pub fn builder() -> MessageBoxColorSchemeBuilder {
+        MessageBoxColorSchemeBuilder::new()
+    }
+}
+
+impl Default for MessageBoxColorScheme {
+    /// The color scheme SDL uses when none is given (on X11).
+    fn default() -> MessageBoxColorScheme {
+        MessageBoxColorScheme {
+            background: (56, 54, 53),
+            text: (209, 207, 205),
+            button_border: (140, 135, 129),
+            button_background: (105, 102, 99),
+            button_selected: (205, 202, 53)
+        }
+    }
+}
+
+/// The type that allows you to build a `MessageBoxColorScheme` color by color.
+///
+/// Every color not explicitly set keeps the value of the scheme the builder started from:
+/// `MessageBoxColorScheme::default()` for `new`, or the given scheme for `from_scheme`.
+///
+/// # Example
+/// ```
+/// use sdl2::messagebox::MessageBoxColorScheme;
+///
+/// let scheme = MessageBoxColorScheme::builder()
+///     .background(255, 255, 255)
+///     .text(0, 0, 0)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageBoxColorSchemeBuilder {
+    scheme: MessageBoxColorScheme
+}
+
+impl MessageBoxColorSchemeBuilder {
+    /// Initializes a new `MessageBoxColorSchemeBuilder` with SDL's default colors.
+    pub fn new() -> MessageBoxColorSchemeBuilder {
+        MessageBoxColorSchemeBuilder::from_scheme(MessageBoxColorScheme::default())
+    }
+
+    /// Initializes a new `MessageBoxColorSchemeBuilder` with the colors of an existing scheme.
+    pub fn from_scheme(scheme: MessageBoxColorScheme) -> MessageBoxColorSchemeBuilder {
+        MessageBoxColorSchemeBuilder { scheme }
+    }
+
+    /// Sets the background color of the dialog.
+    pub fn background(&mut self, r: u8, g: u8, b: u8) -> &mut MessageBoxColorSchemeBuilder {
+        self.scheme.background = (r, g, b);
+        self
+    }
+
+    /// Sets the color of the message text.
+    pub fn text(&mut self, r: u8, g: u8, b: u8) -> &mut MessageBoxColorSchemeBuilder {
+        self.scheme.text = (r, g, b);
+        self
+    }
+
+    /// Sets the border color of the buttons.
+    pub fn button_border(&mut self, r: u8, g: u8, b: u8) -> &mut MessageBoxColorSchemeBuilder {
+        self.scheme.button_border = (r, g, b);
+        self
+    }
+
+    /// Sets the background color of the buttons.
+    pub fn button_background(&mut self, r: u8, g: u8, b: u8) -> &mut MessageBoxColorSchemeBuilder {
+        self.scheme.button_background = (r, g, b);
+        self
+    }
+
+    /// Sets the color of the selected button.
+    pub fn button_selected(&mut self, r: u8, g: u8, b: u8) -> &mut MessageBoxColorSchemeBuilder {
+        self.scheme.button_selected = (r, g, b);
+        self
+    }
+
+    /// Builds the `MessageBoxColorScheme`.
+    pub fn build(&self) -> MessageBoxColorScheme {
+        self.scheme
+    }
+}
+
+impl Default for MessageBoxColorSchemeBuilder {
+    fn default() -> MessageBoxColorSchemeBuilder {
+        MessageBoxColorSchemeBuilder::new()
+    }
+}
+
 impl Into<sys::SDL_MessageBoxColorScheme> for MessageBoxColorScheme {
     fn into(self) -> sys::SDL_MessageBoxColorScheme {
         sys::SDL_MessageBoxColorScheme { colors: self.into() }
@@ -61,10 +166,66 @@ pub struct ButtonData<'a> {
     pub text:&'a str
 }
 
+/// Same as `ButtonData`, but owns its text.
+///
+/// Useful when the list of buttons is built at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ButtonDataOwned {
+    pub flags:MessageBoxButtonFlag,
+    pub button_id:i32,
+    pub text:String
+}
+
+impl ButtonDataOwned {
+    /// Borrows this button as a `ButtonData`.
+    pub fn as_button_data(&self) -> ButtonData<'_> {
+        ButtonData {
+            flags: self.flags,
+            button_id: self.button_id,
+            text: &self.text
+        }
+    }
+}
+
+impl<'a> From<ButtonData<'a>> for ButtonDataOwned {
+    fn from(button: ButtonData<'a>) -> ButtonDataOwned {
+        ButtonDataOwned {
+            flags: button.flags,
+            button_id: button.button_id,
+            text: button.text.to_owned()
+        }
+    }
+}
+
+/// A button that can be passed to `show_message_box`.
+///
+/// Implemented by both `ButtonData` and `ButtonDataOwned`.
+pub trait MessageBoxButton {
+    fn flags(&self) -> MessageBoxButtonFlag;
+    fn button_id(&self) -> i32;
+    fn text(&self) -> &str;
+}
+
+impl<'a> MessageBoxButton for ButtonData<'a> {
+    fn flags(&self) -> MessageBoxButtonFlag { self.flags }
+    fn button_id(&self) -> i32 { self.button_id }
+    fn text(&self) -> &str { self.text }
+}
+
+impl MessageBoxButton for ButtonDataOwned {
+    fn flags(&self) -> MessageBoxButtonFlag { self.flags }
+    fn button_id(&self) -> i32 { self.button_id }
+    fn text(&self) -> &str { &self.text }
+}
+
+/// The button clicked in a message box shown with `show_message_box`.
+///
+/// `B` is the type of the buttons given to `show_message_box`,
+/// either `ButtonData` (the default) or `ButtonDataOwned`.
 #[derive(Debug)]
-pub enum ClickedButton<'a> {
+pub enum ClickedButton<'a, B: 'a = ButtonData<'a>> {
     CloseButton,
-    CustomButton(&'a ButtonData<'a>)
+    CustomButton(&'a B)
 }
 
 impl From<MessageBoxColorScheme> for [sys::SDL_MessageBoxColor ; 5] {
@@ -106,6 +267,12 @@ pub enum ShowMessageError {
     /// Second argument of the tuple (i32) corresponds to the
     /// first button_id having an error
     InvalidButton(NulError,i32),
+    /// Two buttons share this `button_id`, which would make
+    /// the clicked button ambiguous
+    DuplicateButtonId(i32),
+    /// A parent window was given to `EventSubsystem::show_message_box_async`, which
+    /// shows the message box from a thread that doesn't own the window
+    ParentWindowNotAllowed,
     SdlError(String),
 }
 
@@ -118,6 +285,10 @@ impl fmt::Display for ShowMessageError {
             InvalidMessage(ref e) => write!(f, "Invalid message: {}", e),
             InvalidButton(ref e, value) => write!(f,
                 "Invalid button ({}): {}", value, e),
+            DuplicateButtonId(value) => write!(f,
+                "Duplicate button id: {}", value),
+            ParentWindowNotAllowed => write!(f,
+                "A message box shown on a helper thread can't have a parent window"),
             SdlError(ref e) => write!(f, "SDL error: {}", e)
         }
     }
@@ -131,6 +302,8 @@ impl error::Error for ShowMessageError {
             InvalidTitle(_) => "invalid title",
             InvalidMessage(_) => "invalid message",
             InvalidButton(..) => "invalid button",
+            DuplicateButtonId(_) => "duplicate button id",
+            ParentWindowNotAllowed => "parent window not allowed",
             SdlError(ref e) => e
         }
     }
@@ -180,70 +353,324 @@ where W: Into<Option<&'a Window>>
 /// Note that the variant of the `ClickedButton` enum will also be returned if the message box
 /// has been forcefully closed (Alt-F4, ...)
 ///
-pub fn show_message_box<'a, 'b, W, M>(flags:MessageBoxFlag, buttons:&'a [ButtonData], title:&str,
+/// `buttons` can either be a slice of `ButtonData` or of `ButtonDataOwned`.
+/// Every button must have a distinct `button_id`, otherwise
+/// `ShowMessageError::DuplicateButtonId` is returned before the message box is shown.
+///
+pub fn show_message_box<'a, 'b, B, W, M>(flags:MessageBoxFlag, buttons:&'a [B], title:&str,
     message:&str, window: W, scheme: M)
-    -> Result<ClickedButton<'a>,ShowMessageError>
-where W: Into<Option<&'b Window>>,
+    -> Result<ClickedButton<'a, B>,ShowMessageError>
+where B: MessageBoxButton,
+      W: Into<Option<&'b Window>>,
       M: Into<Option<MessageBoxColorScheme>>,
 {
     let window = window.into();
-    let scheme = scheme.into();
-
-    use self::ShowMessageError::*;
-    let mut button_id : c_int = 0;
-    let title = match CString::new(title) {
-        Ok(s) => s,
-        Err(err) => return Err(InvalidTitle(err)),
-    };
-    let message = match CString::new(message) {
-        Ok(s) => s,
-        Err(err) => return Err(InvalidMessage(err)),
+    let data = MessageBoxData::new(flags, buttons, title, message, scheme.into())?;
+    let button_id = unsafe {
+        data.show(window.map_or(ptr::null_mut(), |win| win.raw()))?
     };
-    let button_texts : Result<Vec<_>,(_,i32)> = buttons.iter().map(|b|{
-        CString::new(b.text).map_err(|e|(e,b.button_id))
-    }).collect(); // Create CString for every button; and catch any CString Error
-    let button_texts = match button_texts {
-        Ok(b) => b,
-        Err(e) => return Err(InvalidButton(e.0,e.1))
-    };
-    let raw_buttons : Vec<sys::SDL_MessageBoxButtonData> =
-        buttons.iter().zip(button_texts.iter()).map(|(b,b_text)|{
-        sys::SDL_MessageBoxButtonData {
-            flags:b.flags.bits(),
-            buttonid:b.button_id as c_int,
-            text:b_text.as_ptr()
-        }
-    }).collect();
-    let result = unsafe {
+    match button_id {
+        -1 => Ok(ClickedButton::CloseButton),
+        id => {
+            let button = buttons.iter().find(|b| b.button_id() == id);
+            Ok(ClickedButton::CustomButton(button.unwrap()))
+        }
+    }
+}
+
+/// Identifies the button clicked in a message box shown with
+/// `EventSubsystem::show_message_box_async`.
+///
+/// Unlike `ClickedButton`, this does not borrow the buttons: the dialog
+/// outlives the call that created it, so the button is identified by its `button_id`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ClickedButtonId {
+    CloseButton,
+    CustomButton(i32)
+}
+
+impl ClickedButtonId {
+    /// Looks the clicked button up in `buttons`, which should be the buttons given to
+    /// `show_message_box_async`.
+    ///
+    /// Returns `None` if no button in `buttons` has the clicked `button_id`.
+    pub fn resolve<'a, B: MessageBoxButton>(self, buttons: &'a [B]) -> Option<ClickedButton<'a, B>> {
+        match self {
+            ClickedButtonId::CloseButton => Some(ClickedButton::CloseButton),
+            ClickedButtonId::CustomButton(id) => {
+                buttons.iter().find(|b| b.button_id() == id).map(ClickedButton::CustomButton)
+            }
+        }
+    }
+}
+
+type MessageBoxReceiver = mpsc::Receiver<Result<ClickedButtonId, ShowMessageError>>;
+
+thread_local! {
+    /// Message boxes whose handle was dropped while they were still open. Their
+    /// `EventSubsystem` is kept here so that SDL isn't shut down under the helper thread.
+    static ORPHANED_MESSAGE_BOXES: RefCell<Vec<(EventSubsystem, MessageBoxReceiver)>> =
+        RefCell::new(Vec::new());
+}
+
+/// Releases the subsystems of orphaned message boxes that have been dismissed since.
+fn release_dismissed_message_boxes() {
+    ORPHANED_MESSAGE_BOXES.with(|orphans| {
+        orphans.borrow_mut().retain(|&(_, ref receiver)| match receiver.try_recv() {
+            Err(mpsc::TryRecvError::Empty) => true,
+            _ => false
+        });
+    });
+}
+
+/// A message box shown on a helper thread, returned by
+/// `EventSubsystem::show_message_box_async`.
+///
+/// Dropping the handle does not close the dialog; its result is simply discarded. The event
+/// subsystem, and with it SDL, stays initialized until the dialog is dismissed. It is released
+/// the next time a handle is dropped or a message box is shown on this thread.
+pub struct MessageBoxHandle {
+    subsystem: EventSubsystem,
+    receiver: Option<MessageBoxReceiver>,
+    finished: bool
+}
+
+impl MessageBoxHandle {
+    /// Returns the result of the message box if it has been dismissed,
+    /// or `None` if it is still open.
+    ///
+    /// This never blocks. The result is returned exactly once; subsequent calls return `None`.
+    pub fn try_result(&mut self) -> Option<Result<ClickedButtonId, ShowMessageError>> {
+        if self.finished {
+            return None;
+        }
+        match self.receiver.as_ref().unwrap().try_recv() {
+            Ok(result) => {
+                self.finished = true;
+                Some(result)
+            },
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.finished = true;
+                Some(Err(ShowMessageError::SdlError(
+                    "message box thread exited without a result".to_owned())))
+            }
+        }
+    }
+
+    /// Blocks until the message box has been dismissed and returns its result.
+    pub fn wait(mut self) -> Result<ClickedButtonId, ShowMessageError> {
+        if self.finished {
+            return Err(ShowMessageError::SdlError(
+                "the message box result has already been retrieved".to_owned()));
+        }
+        self.finished = true;
+        self.receiver.as_ref().unwrap().recv().unwrap_or_else(|_| Err(ShowMessageError::SdlError(
+            "message box thread exited without a result".to_owned())))
+    }
+}
+
+impl Drop for MessageBoxHandle {
+    fn drop(&mut self) {
+        release_dismissed_message_boxes();
+        if !self.finished {
+            let orphan = (self.subsystem.clone(), self.receiver.take().unwrap());
+            ORPHANED_MESSAGE_BOXES.with(|orphans| orphans.borrow_mut().push(orphan));
+        }
+    }
+}
+
+impl EventSubsystem {
+    /// Show a customizable message box without blocking the calling thread.
+    ///
+    /// The dialog is run by `SDL_ShowMessageBox` on a helper thread, and its result
+    /// can be polled with `MessageBoxHandle::try_result`. The clicked button is
+    /// reported by its id; `ClickedButtonId::resolve` finds it in `buttons`. The
+    /// title, message and button texts are validated before the thread is spawned,
+    /// so those errors are returned immediately.
+    ///
+    /// # Threading
+    ///
+    /// SDL requires a message box with a parent window to be shown from the thread
+    /// that created that window, which the helper thread never is. `window` must
+    /// therefore be `None`; passing a window returns
+    /// `ShowMessageError::ParentWindowNotAllowed`. Use `show_message_box` on the
+    /// window's thread for a modal dialog.
+    ///
+    /// Some platforms (e.g. macOS) forward the dialog to the main thread, in which
+    /// case it is only displayed while the main thread keeps pumping events.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use sdl2::messagebox::{ButtonData, ClickedButton, MessageBoxButtonFlag, MessageBoxFlag};
+    ///
+    /// let sdl = sdl2::init().unwrap();
+    /// let events = sdl.event().unwrap();
+    /// let buttons = [
+    ///     ButtonData { flags: MessageBoxButtonFlag::NOTHING, button_id: 1, text: "Save" },
+    ///     ButtonData { flags: MessageBoxButtonFlag::NOTHING, button_id: 2, text: "Discard" },
+    /// ];
+    /// let mut handle = events.show_message_box_async(MessageBoxFlag::WARNING, &buttons,
+    ///     "Unsaved changes", "Save before quitting?", None, None).unwrap();
+    ///
+    /// loop {
+    ///     // ... render a frame ...
+    ///     if let Some(result) = handle.try_result() {
+    ///         match result.unwrap().resolve(&buttons) {
+    ///             Some(ClickedButton::CustomButton(button)) => println!("{}", button.text),
+    ///             _ => println!("closed"),
+    ///         }
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    pub fn show_message_box_async<'b, B, W, M>(&self, flags: MessageBoxFlag, buttons: &[B],
+        title: &str, message: &str, window: W, scheme: M)
+        -> Result<MessageBoxHandle, ShowMessageError>
+    where B: MessageBoxButton,
+          W: Into<Option<&'b Window>>,
+          M: Into<Option<MessageBoxColorScheme>>,
+    {
+        if window.into().is_some() {
+            return Err(ShowMessageError::ParentWindowNotAllowed);
+        }
+        let data = MessageBoxData::new(flags, buttons, title, message, scheme.into())?;
+        release_dismissed_message_boxes();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::Builder::new()
+            .name("sdl2-message-box".to_owned())
+            .spawn(move || {
+                let result = unsafe { data.show(ptr::null_mut()) }.map(|id| match id {
+                    -1 => ClickedButtonId::CloseButton,
+                    id => ClickedButtonId::CustomButton(id)
+                });
+                // The handle may have been dropped; nobody is interested in the result then.
+                let _ = sender.send(result);
+            })
+            .map_err(|e| ShowMessageError::SdlError(
+                format!("could not spawn message box thread: {}", e)))?;
+
+        Ok(MessageBoxHandle {
+            subsystem: self.clone(),
+            receiver: Some(receiver),
+            finished: false
+        })
+    }
+}
+
+/// Owned, validated arguments of `SDL_ShowMessageBox`.
+struct MessageBoxData {
+    flags: MessageBoxFlag,
+    title: CString,
+    message: CString,
+    buttons: Vec<(MessageBoxButtonFlag, i32, CString)>,
+    scheme: Option<MessageBoxColorScheme>
+}
+
+impl MessageBoxData {
+    fn new<B: MessageBoxButton>(flags: MessageBoxFlag, buttons: &[B], title: &str, message: &str,
+        scheme: Option<MessageBoxColorScheme>)
+        -> Result<MessageBoxData, ShowMessageError>
+    {
+        use self::ShowMessageError::*;
+        let title = match CString::new(title) {
+            Ok(s) => s,
+            Err(err) => return Err(InvalidTitle(err)),
+        };
+        let message = match CString::new(message) {
+            Ok(s) => s,
+            Err(err) => return Err(InvalidMessage(err)),
+        };
+        let mut button_ids = HashSet::with_capacity(buttons.len());
+        if let Some(b) = buttons.iter().find(|b| !button_ids.insert(b.button_id())) {
+            return Err(DuplicateButtonId(b.button_id()));
+        }
+        let buttons : Result<Vec<_>,(_,i32)> = buttons.iter().map(|b|{
+            CString::new(b.text())
+                .map(|text| (b.flags(), b.button_id(), text))
+                .map_err(|e|(e,b.button_id()))
+        }).collect(); // Create CString for every button; and catch any CString Error
+        let buttons = match buttons {
+            Ok(b) => b,
+            Err(e) => return Err(InvalidButton(e.0,e.1))
+        };
+        Ok(MessageBoxData { flags, title, message, buttons, scheme })
+    }
+
+    /// Shows the message box and returns the clicked `button_id`, or -1 for the close button.
+    ///
+    /// `window` must be null, or a window created on the calling thread.
+    unsafe fn show(&self, window: *mut sys::SDL_Window) -> Result<i32, ShowMessageError> {
+        let mut button_id : c_int = 0;
+        let raw_buttons : Vec<sys::SDL_MessageBoxButtonData> =
+            self.buttons.iter().map(|&(flags, id, ref text)|{
+            sys::SDL_MessageBoxButtonData {
+                flags:flags.bits(),
+                buttonid:id as c_int,
+                text:text.as_ptr()
+            }
+        }).collect();
+        let raw_scheme = self.scheme.map(|scheme| sys::SDL_MessageBoxColorScheme {
+            colors:From::from(scheme)
+        });
         let msg_box_data = sys::SDL_MessageBoxData {
-            flags:flags.bits(),
-            window:window.map_or(ptr::null_mut(), |win| win.raw()),
-            title: title.as_ptr() as *const c_char,
-            message: message.as_ptr() as *const c_char,
+            flags:self.flags.bits(),
+            window,
+            title: self.title.as_ptr() as *const c_char,
+            message: self.message.as_ptr() as *const c_char,
             numbuttons: raw_buttons.len() as c_int,
             buttons: raw_buttons.as_ptr(),
-            colorScheme: if let Some(scheme) = scheme {
-                &sys::SDL_MessageBoxColorScheme {
-                    colors:From::from(scheme)
-                } as *const _
-            } else {
-                ptr::null()
-            }
+            colorScheme: raw_scheme.as_ref().map_or(ptr::null(), |s| s as *const _)
         };
-        sys::SDL_ShowMessageBox(
+        let result = sys::SDL_ShowMessageBox(
             &msg_box_data as *const _,
             &mut button_id as &mut _
-        )
-    } == 0;
-    if result {
-        match button_id {
-            -1 => Ok(ClickedButton::CloseButton),
-            id => {
-                let button = buttons.iter().find(|b| b.button_id == id);
-                Ok(ClickedButton::CustomButton(button.unwrap()))
-            }
+        ) == 0;
+        if result {
+            Ok(button_id)
+        } else {
+            Err(ShowMessageError::SdlError(get_error()))
         }
-    } else {
-        Err(SdlError(get_error()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ButtonData, ClickedButton, ClickedButtonId, MessageBoxButtonFlag, MessageBoxData,
+                MessageBoxFlag, ShowMessageError};
+
+    #[test]
+    fn duplicate_button_id() {
+        let buttons = [
+            ButtonData { flags: MessageBoxButtonFlag::NOTHING, button_id: 1, text: "Save" },
+            ButtonData { flags: MessageBoxButtonFlag::NOTHING, button_id: 2, text: "Save As" },
+            ButtonData { flags: MessageBoxButtonFlag::NOTHING, button_id: 1, text: "Cancel" },
+        ];
+        match MessageBoxData::new(MessageBoxFlag::INFORMATION, &buttons, "title", "message", None) {
+            Err(ShowMessageError::DuplicateButtonId(1)) => {},
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("duplicate button id not detected"),
+        }
+        assert!(MessageBoxData::new(MessageBoxFlag::INFORMATION, &buttons[..2],
+                                    "title", "message", None).is_ok());
+    }
+
+    #[test]
+    fn resolve_clicked_button_id() {
+        let buttons = [
+            ButtonData { flags: MessageBoxButtonFlag::NOTHING, button_id: 1, text: "Save" },
+            ButtonData { flags: MessageBoxButtonFlag::NOTHING, button_id: 2, text: "Cancel" },
+        ];
+        match ClickedButtonId::CustomButton(2).resolve(&buttons) {
+            Some(ClickedButton::CustomButton(button)) => assert_eq!(button.text, "Cancel"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match ClickedButtonId::CloseButton.resolve(&buttons) {
+            Some(ClickedButton::CloseButton) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(ClickedButtonId::CustomButton(3).resolve(&buttons).is_none());
     }
 }
@@ -54,7 +54,10 @@ impl Cursor {
         }
     }
 
-    // TODO: figure out how to pass Surface in here correctly
+    /// Creates a color cursor from `surface`, with its hot spot at (`hot_x`, `hot_y`).
+    ///
+    /// SDL copies the pixels, so `surface` may be dropped once the cursor is created.
+    /// The returned `Cursor` frees the SDL cursor when dropped.
     pub fn from_surface<S: AsRef<SurfaceRef>>(surface: S, hot_x: i32, hot_y: i32) -> Result<Cursor, String> {
         unsafe {
             let raw = sys::SDL_CreateColorCursor(surface.as_ref().raw(), hot_x, hot_y);
@@ -79,6 +82,9 @@ impl Cursor {
         }
     }
 
+    /// Makes this the active cursor.
+    ///
+    /// The cursor must stay alive for as long as it is displayed.
     pub fn set(&self) {
         unsafe { sys::SDL_SetCursor(self.raw); }
     }
@@ -226,6 +232,8 @@ impl MouseState {
     /// }
     /// ```
     pub fn is_mouse_button_pressed(&self, mouse_button: MouseButton) -> bool {
+        // `Unknown` has no bit in the mask (and would underflow the shift)
+        if mouse_button == MouseButton::Unknown { return false; }
         let mask = 1 << ((mouse_button as u32)-1);
         self.mouse_state & mask != 0
     }
@@ -348,15 +356,52 @@ impl MouseUtil {
         }
     }
 
+    /// Returns the state of the mouse buttons and the position of the cursor in
+    /// global screen coordinates, regardless of which window has the focus.
+    ///
+    /// Unlike `EventPump::mouse_state`, this queries the OS directly, so it
+    /// stays accurate even while the cursor is outside of every SDL window.
+    pub fn global_mouse_state(&self) -> MouseState {
+        let mut x = 0;
+        let mut y = 0;
+        let mouse_state = unsafe { sys::SDL_GetGlobalMouseState(&mut x, &mut y) };
+
+        MouseState {
+            mouse_state,
+            x: x as i32,
+            y: y as i32
+        }
+    }
+
+    /// Moves the mouse cursor to the given position, in window coordinates.
     pub fn warp_mouse_in_window(&self, window: &video::Window, x: i32, y: i32) {
         unsafe { sys::SDL_WarpMouseInWindow(window.raw(), x, y); }
     }
 
-    pub fn set_relative_mouse_mode(&self, on: bool) {
+    /// Moves the mouse cursor to the given position, in global screen coordinates.
+    ///
+    /// Returns an error if warping the mouse isn't supported by the current platform.
+    pub fn warp_mouse_global(&self, x: i32, y: i32) -> Result<(), String> {
+        let result = unsafe { sys::SDL_WarpMouseGlobal(x, y) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Enables or disables relative mouse mode.
+    ///
+    /// While enabled, the cursor is hidden and pinned to the window, and the mouse movement
+    /// is reported by the `xrel` and `yrel` fields of `Event::MouseMotion`.
+    ///
+    /// Returns `false` if relative mouse mode isn't supported.
+    pub fn set_relative_mouse_mode(&self, on: bool) -> bool {
         let on = if on { sys::SDL_bool::SDL_TRUE } else { sys::SDL_bool::SDL_FALSE };
-        unsafe { sys::SDL_SetRelativeMouseMode(on); }
+        unsafe { sys::SDL_SetRelativeMouseMode(on) == 0 }
     }
 
+    /// Returns whether relative mouse mode is enabled.
     pub fn relative_mouse_mode(&self) -> bool {
         unsafe { sys::SDL_GetRelativeMouseMode() == sys::SDL_bool::SDL_TRUE }
     }
@@ -369,6 +414,10 @@ impl MouseUtil {
         unsafe { sys::SDL_ShowCursor(show as i32); }
     }
 
+    /// Captures the mouse, so that mouse events keep being reported while the
+    /// cursor is outside of the focused window, e.g. while dragging.
+    ///
+    /// Only the window with the input focus can capture the mouse.
     pub fn capture(&self, enable: bool) {
         let enable = if enable { sys::SDL_bool::SDL_TRUE } else { sys::SDL_bool::SDL_FALSE };
         unsafe { sys::SDL_CaptureMouse(enable); }
@@ -79,6 +79,8 @@ impl RelativeMouseState {
     /// }
     /// ```
     pub fn is_mouse_button_pressed(&self, mouse_button: MouseButton) -> bool {
+        // `Unknown` has no bit in the mask (and would underflow the shift)
+        if mouse_button == MouseButton::Unknown { return false; }
         let mask = 1 << ((mouse_button as u32)-1);
         self.mouse_state & mask != 0
     }
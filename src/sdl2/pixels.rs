@@ -39,6 +39,8 @@ impl Palette {
     }
 
     /// Creates a palette from the provided colors
+    ///
+    /// Errors if `colors` is empty.
     pub fn with_colors(colors: &[Color]) -> Result<Self, String> {
         let pal = Self::new(colors.len())?;
 
@@ -62,6 +64,35 @@ impl Palette {
         }
     }
 
+    /// Replaces the colors of this palette starting at index `first`.
+    ///
+    /// Surfaces using this palette see the change the next time they are
+    /// blitted or converted, which makes this suitable for palette cycling
+    /// and similar effects.
+    ///
+    /// Errors if the colors don't fit in the palette.
+    pub fn set_colors(&mut self, first: usize, colors: &[Color]) -> Result<(), String> {
+        if first.saturating_add(colors.len()) > self.len() {
+            return Err(format!("{} colors starting at index {} do not fit in a palette of {}",
+                               colors.len(), first, self.len()));
+        }
+
+        let raw_colors: Vec<sys::SDL_Color> = colors.iter()
+            .map(|color| color.raw())
+            .collect();
+
+        let result = unsafe {
+            sys::SDL_SetPaletteColors(self.raw, raw_colors.as_ptr(),
+                                      first as ::libc::c_int, colors.len() as ::libc::c_int)
+        };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn len(&self) -> usize {
         unsafe { (*self.raw).ncolors as usize }
     }
@@ -90,6 +121,14 @@ fn create_palette() {
     assert!(palette.len() == 255);
 }
 
+#[test]
+fn set_palette_colors() {
+    let mut palette = Palette::new(4).unwrap();
+
+    assert!(palette.set_colors(2, &[Color::RED, Color::BLUE]).is_ok());
+    assert!(palette.set_colors(3, &[Color::RED, Color::BLUE]).is_err());
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Color {
     pub r: u8,
@@ -128,6 +167,49 @@ impl Color {
         Color::RGBA(255 - self.r, 255 - self.g, 255 - self.b, 255 - self.a)
     }
 
+    /// Returns this color with its alpha channel replaced by `a`.
+    #[inline]
+    pub const fn with_alpha(self, a: u8) -> Color {
+        Color { r: self.r, g: self.g, b: self.b, a }
+    }
+
+    /// Linearly interpolates every channel, alpha included, from `self`
+    /// towards `other`.
+    ///
+    /// `t` is clamped to `0.0..=1.0`: `0.0` returns `self` and `1.0` returns
+    /// `other`. Channels are rounded to the nearest integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl2::pixels::Color;
+    /// assert_eq!(Color::BLACK.blend(Color::WHITE, 0.5), Color::RGB(128, 128, 128));
+    /// assert_eq!(Color::BLACK.blend(Color::WHITE, 2.0), Color::WHITE);
+    /// ```
+    pub fn blend(self, other: Color, t: f32) -> Color {
+        let t = t.max(0.0).min(1.0);
+        let lerp = |from: u8, to: u8| {
+            (from as f32 + (to as f32 - from as f32) * t).round() as u8
+        };
+        Color::RGBA(lerp(self.r, other.r), lerp(self.g, other.g),
+                    lerp(self.b, other.b), lerp(self.a, other.a))
+    }
+
+    /// Returns this color with the red, green and blue channels multiplied by
+    /// its alpha, as expected by premultiplied-alpha blending. Alpha itself is
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl2::pixels::Color;
+    /// assert_eq!(Color::RGBA(255, 100, 0, 128).premultiplied(), Color::RGBA(128, 50, 0, 128));
+    /// ```
+    pub fn premultiplied(self) -> Color {
+        let mul = |c: u8| ((c as u16 * self.a as u16 + 127) / 255) as u8;
+        Color::RGBA(mul(self.r), mul(self.g), mul(self.b), self.a)
+    }
+
     #[inline]
     pub const fn rgb(self) -> (u8, u8, u8) {
         (self.r, self.g, self.b)
@@ -202,6 +284,53 @@ pub struct PixelFormat {
 impl_raw_accessors!((PixelFormat, *mut sys::SDL_PixelFormat));
 impl_raw_constructor!((PixelFormat, PixelFormat (raw: *mut sys::SDL_PixelFormat)));
 
+impl PixelFormat {
+    /// Packs `color` into a pixel value in this format.
+    ///
+    /// Channels that the format doesn't have are dropped, and for indexed
+    /// formats the closest palette entry is chosen. This is the same as
+    /// `color.to_u32(self)`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::TryFrom;
+    /// use sdl2::pixels::{Color, PixelFormat, PixelFormatEnum};
+    ///
+    /// let format = PixelFormat::try_from(PixelFormatEnum::ARGB8888).unwrap();
+    /// assert_eq!(format.map_rgba(Color::RGBA(1, 2, 3, 4)), 0x04010203);
+    /// ```
+    pub fn map_rgba(&self, color: Color) -> u32 {
+        unsafe { sys::SDL_MapRGBA(self.raw, color.r, color.g, color.b, color.a) }
+    }
+
+    /// Like `map_rgba`, but the pixel is fully opaque if the format has an
+    /// alpha channel.
+    pub fn map_rgb(&self, color: Color) -> u32 {
+        unsafe { sys::SDL_MapRGB(self.raw, color.r, color.g, color.b) }
+    }
+
+    /// Unpacks a pixel value in this format into a color.
+    ///
+    /// Channels are expanded to the full 0-255 range; if the format has no
+    /// alpha channel, the returned alpha is 255. This is the same as
+    /// `Color::from_u32(self, pixel)`.
+    pub fn get_rgba(&self, pixel: u32) -> Color {
+        Color::from_u32(self, pixel)
+    }
+
+    /// Like `get_rgba`, but ignores the alpha channel; the returned color is
+    /// always opaque.
+    pub fn get_rgb(&self, pixel: u32) -> Color {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+
+        unsafe {
+            sys::SDL_GetRGB(pixel, self.raw, &mut r, &mut g, &mut b)
+        };
+        Color::RGB(r, g, b)
+    }
+}
+
 #[repr(i32)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum PixelFormatEnum {
@@ -307,6 +436,12 @@ impl PixelFormatEnum {
         }
     }
 
+    /// Calculates the byte size of `num_of_pixels` consecutive pixels of this format.
+    ///
+    /// For the 1 and 4 bits indexed formats, the size is rounded up to the
+    /// next full byte.
+    ///
+    /// Panics on `PixelFormatEnum::Unknown`.
     #[allow(clippy::match_same_arms)]
     pub fn byte_size_of_pixels(self, num_of_pixels: usize) -> usize {
         match self {
@@ -335,16 +470,24 @@ impl PixelFormatEnum {
             PixelFormatEnum::YUY2 | PixelFormatEnum::UYVY |
             PixelFormatEnum::YVYU
                 => num_of_pixels * 2,
-            // Unsupported formats
+            // Indexed formats
             PixelFormatEnum::Index8
                 => num_of_pixels,
-            PixelFormatEnum::Unknown | PixelFormatEnum::Index1LSB |
-            PixelFormatEnum::Index1MSB | PixelFormatEnum::Index4LSB |
-            PixelFormatEnum::Index4MSB
+            PixelFormatEnum::Index4LSB | PixelFormatEnum::Index4MSB
+                => (num_of_pixels + 1) / 2,
+            PixelFormatEnum::Index1LSB | PixelFormatEnum::Index1MSB
+                => (num_of_pixels + 7) / 8,
+            // Unsupported formats
+            PixelFormatEnum::Unknown
                 => panic!("not supported format: {:?}", self),
         }
     }
 
+    /// Returns the number of bytes used by a single pixel of this format,
+    /// as `SDL_BYTESPERPIXEL` does.
+    ///
+    /// Panics on `PixelFormatEnum::Unknown` and on the 1 and 4 bits indexed formats,
+    /// whose pixels don't fill a whole byte: use `byte_size_of_pixels` for those.
     #[allow(clippy::match_same_arms)]
     pub fn byte_size_per_pixel(self) -> usize {
         match self {
@@ -382,6 +525,7 @@ impl PixelFormatEnum {
         }
     }
 
+    /// Returns `true` if this format has an alpha channel, as `SDL_ISPIXELFORMAT_ALPHA` does.
     pub fn supports_alpha(self) -> bool {
         use crate::pixels::PixelFormatEnum::*;
         match self {
@@ -511,3 +655,19 @@ fn test_pixel_format_enum() {
         }
     }
 }
+
+#[test]
+fn test_byte_size_of_pixels() {
+    assert_eq!(PixelFormatEnum::RGB24.byte_size_of_pixels(10), 30);
+    assert_eq!(PixelFormatEnum::ARGB8888.byte_size_of_pixels(10), 40);
+    assert_eq!(PixelFormatEnum::RGB565.byte_size_of_pixels(10), 20);
+    assert_eq!(PixelFormatEnum::Index8.byte_size_of_pixels(10), 10);
+    assert_eq!(PixelFormatEnum::Index4LSB.byte_size_of_pixels(9), 5);
+    assert_eq!(PixelFormatEnum::Index1MSB.byte_size_of_pixels(9), 2);
+    assert_eq!(PixelFormatEnum::Index1MSB.byte_size_of_pixels(8), 1);
+
+    assert_eq!(PixelFormatEnum::RGB24.byte_size_per_pixel(), 3);
+    assert_eq!(PixelFormatEnum::ARGB8888.byte_size_per_pixel(), 4);
+    assert!(PixelFormatEnum::ARGB8888.supports_alpha());
+    assert!(!PixelFormatEnum::RGB24.supports_alpha());
+}
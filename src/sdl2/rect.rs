@@ -440,6 +440,22 @@ impl Rect {
     /// Calculate a minimal rectangle enclosing a set of points.
     /// If a clipping rectangle is given, only points that are within it will be
     /// considered.
+    ///
+    /// Returns `None` if `points` is empty or if every point lies outside of
+    /// the clipping rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sdl2::rect::{Point, Rect};
+    /// let points = [Point::new(1, 2), Point::new(4, 8), Point::new(20, 20)];
+    /// assert_eq!(Rect::from_enclose_points(&points, None),
+    ///     Some(Rect::new(1, 2, 20, 19)));
+    /// assert_eq!(Rect::from_enclose_points(&points, Rect::new(0, 0, 10, 10)),
+    ///     Some(Rect::new(1, 2, 4, 7)));
+    /// assert_eq!(Rect::from_enclose_points(&points, Rect::new(30, 30, 5, 5)),
+    ///     None);
+    /// ```
     pub fn from_enclose_points<R: Into<Option<Rect>>>(points: &[Point], clipping_rect: R)
             -> Option<Rect>
     where R: Into<Option<Rect>>
@@ -469,7 +485,6 @@ impl Rect {
         if result {
             let out = unsafe { out.assume_init() };
 
-            // Return an error if the dimensions are too large.
             Some(Rect::from_ll(out))
         } else {
             None
@@ -559,6 +574,21 @@ impl Rect {
 
     /// Calculates the intersection of a rectangle and a line segment and
     /// returns the points of their intersection.
+    ///
+    /// The returned segment is the part of the line from `start` to `end`
+    /// that lies within the rectangle. Returns `None` if the line doesn't
+    /// cross the rectangle at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sdl2::rect::{Point, Rect};
+    /// let rect = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(rect.intersect_line(Point::new(-5, 5), Point::new(5, 5)),
+    ///     Some((Point::new(0, 5), Point::new(5, 5))));
+    /// assert_eq!(rect.intersect_line(Point::new(-5, -5), Point::new(-5, 20)),
+    ///     None);
+    /// ```
     pub fn intersect_line(&self, start: Point, end: Point)
             -> Option<(Point, Point)> {
 
@@ -659,6 +689,23 @@ impl BitOr<Rect> for Rect {
 }
 
 /// Immutable point type, consisting of x and y.
+///
+/// Points support integer vector arithmetic through `+`, `-`, unary `-`,
+/// and `*`/`/` by an `i32`. Coordinates are always kept between
+/// `min_int_value()` and `max_int_value()`: any result outside of that range
+/// saturates to the nearest bound instead of overflowing. Dividing by zero
+/// panics, like integer division does.
+///
+/// # Examples
+///
+/// ```
+/// use sdl2::rect::Point;
+/// let position = Point::new(10, 20);
+/// let velocity = Point::new(3, -4);
+/// assert_eq!(position + velocity, Point::new(13, 16));
+/// assert_eq!(position - velocity * 2, Point::new(4, 28));
+/// assert_eq!(-velocity, Point::new(-3, 4));
+/// ```
 #[derive(Copy, Clone)]
 pub struct Point {
     raw: sys::SDL_Point
@@ -775,6 +822,9 @@ impl Point {
 
     /// Returns a new point by shifting this point's coordinates by the given
     /// x and y values.
+    ///
+    /// The resulting coordinates saturate at `min_int_value()` and
+    /// `max_int_value()`.
     pub fn offset(self, x: i32, y: i32) -> Point {
         let x = match self.raw.x.checked_add(x) {
             Some(val) => val,
@@ -801,6 +851,9 @@ impl Point {
 
     /// Returns a new point by multiplying this point's coordinates by the
     /// given scale factor.
+    ///
+    /// The resulting coordinates saturate at `min_int_value()` and
+    /// `max_int_value()`.
     pub fn scale(self, f: i32) -> Point {
         Point::new(clamped_mul(self.raw.x, f),
                    clamped_mul(self.raw.y, f))
@@ -885,9 +938,542 @@ impl DivAssign<i32> for Point {
     }
 }
 
+/// A rectangle with floating point coordinates, as used by the `_f` rendering functions.
+///
+/// Unlike `Rect`, the position and size of an `FRect` are not clamped, and a
+/// rectangle with a zero or negative size is allowed; it simply contains no
+/// points.
+///
+/// # Examples
+///
+/// ```
+/// use sdl2::rect::{FPoint, FRect, Rect};
+/// let rect = FRect::new(0.5, 0.5, 2.0, 2.0);
+/// assert!(rect.contains_point(FPoint::new(1.0, 2.0)));
+/// assert_eq!(FRect::from(Rect::new(1, 2, 3, 4)), FRect::new(1.0, 2.0, 3.0, 4.0));
+/// ```
+#[derive(Clone, Copy)]
+pub struct FRect {
+    raw: sys::SDL_FRect,
+}
+
+impl ::std::fmt::Debug for FRect {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+        return write!(fmt, "FRect {{ x: {}, y: {}, w: {}, h: {} }}",
+            self.raw.x, self.raw.y, self.raw.w, self.raw.h);
+    }
+}
+
+impl PartialEq for FRect {
+    fn eq(&self, other: &FRect) -> bool {
+        self.raw.x == other.raw.x &&
+        self.raw.y == other.raw.y &&
+        self.raw.w == other.raw.w &&
+        self.raw.h == other.raw.h
+    }
+}
+
+impl FRect {
+    /// Creates a new rectangle from the given values.
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> FRect {
+        FRect {
+            raw: sys::SDL_FRect { x, y, w: width, h: height }
+        }
+    }
+
+    /// Creates a new rectangle centered on the given position.
+    pub fn from_center<P>(center: P, width: f32, height: f32) -> FRect
+            where P: Into<FPoint> {
+        let center = center.into();
+        FRect::new(center.x() - width / 2.0, center.y() - height / 2.0, width, height)
+    }
+
+    /// The horizontal position of this rectangle.
+    pub fn x(&self) -> f32 {
+        self.raw.x
+    }
+
+    /// The vertical position of this rectangle.
+    pub fn y(&self) -> f32 {
+        self.raw.y
+    }
+
+    /// The width of this rectangle.
+    pub fn width(&self) -> f32 {
+        self.raw.w
+    }
+
+    /// The height of this rectangle.
+    pub fn height(&self) -> f32 {
+        self.raw.h
+    }
+
+    /// Returns the width and height of this rectangle.
+    pub fn size(&self) -> (f32, f32) {
+        (self.width(), self.height())
+    }
+
+    /// Sets the horizontal position of this rectangle to the given value.
+    pub fn set_x(&mut self, x: f32) {
+        self.raw.x = x;
+    }
+
+    /// Sets the vertical position of this rectangle to the given value.
+    pub fn set_y(&mut self, y: f32) {
+        self.raw.y = y;
+    }
+
+    /// Sets the width of this rectangle to the given value.
+    pub fn set_width(&mut self, width: f32) {
+        self.raw.w = width;
+    }
+
+    /// Sets the height of this rectangle to the given value.
+    pub fn set_height(&mut self, height: f32) {
+        self.raw.h = height;
+    }
+
+    /// Returns the x-position of the left side of this rectangle.
+    pub fn left(&self) -> f32 {
+        self.raw.x
+    }
+
+    /// Returns the x-position of the right side of this rectangle.
+    pub fn right(&self) -> f32 {
+        self.raw.x + self.raw.w
+    }
+
+    /// Returns the y-position of the top side of this rectangle.
+    pub fn top(&self) -> f32 {
+        self.raw.y
+    }
+
+    /// Returns the y-position of the bottom side of this rectangle.
+    pub fn bottom(&self) -> f32 {
+        self.raw.y + self.raw.h
+    }
+
+    /// Returns the center position of this rectangle.
+    pub fn center(&self) -> FPoint {
+        FPoint::new(self.raw.x + self.raw.w / 2.0, self.raw.y + self.raw.h / 2.0)
+    }
+
+    /// Returns `true` if this rectangle has no area.
+    pub fn is_empty(&self) -> bool {
+        !(self.raw.w > 0.0 && self.raw.h > 0.0)
+    }
+
+    /// Moves this rectangle by the given amount.
+    pub fn offset(&mut self, x: f32, y: f32) {
+        self.raw.x += x;
+        self.raw.y += y;
+    }
+
+    /// Moves this rectangle to the given position.
+    pub fn reposition<P>(&mut self, point: P) where P: Into<FPoint> {
+        let point = point.into();
+        self.raw.x = point.x();
+        self.raw.y = point.y();
+    }
+
+    /// Resizes this rectangle to the given size.
+    pub fn resize(&mut self, width: f32, height: f32) {
+        self.raw.w = width;
+        self.raw.h = height;
+    }
+
+    /// Checks whether this rectangle contains a given point.
+    ///
+    /// Like `Rect::contains_point`, points along the right and bottom edges
+    /// are not considered to be inside the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sdl2::rect::{FRect, FPoint};
+    /// let rect = FRect::new(1.0, 2.0, 3.0, 4.0);
+    /// assert!(rect.contains_point(FPoint::new(1.0, 2.0)));
+    /// assert!(rect.contains_point(FPoint::new(3.5, 5.5)));
+    /// assert!(!rect.contains_point(FPoint::new(4.0, 6.0)));
+    /// ```
+    pub fn contains_point<P>(&self, point: P) -> bool
+            where P: Into<FPoint> {
+        let point = point.into();
+        let inside_x = point.x() >= self.left() && point.x() < self.right();
+        inside_x && (point.y() >= self.top() && point.y() < self.bottom())
+    }
+
+    /// Checks whether this rectangle completely contains another rectangle.
+    pub fn contains_rect(&self, other: FRect) -> bool {
+        other.left() >= self.left() && other.right() <= self.right() &&
+            other.top() >= self.top() && other.bottom() <= self.bottom()
+    }
+
+    /// Checks whether this rectangle intersects with another rectangle.
+    ///
+    /// Rectangles that only share an edge are not considered to intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sdl2::rect::FRect;
+    /// let rect = FRect::new(0.0, 0.0, 5.0, 5.0);
+    /// assert!(rect.has_intersection(FRect::new(4.5, 4.5, 5.0, 5.0)));
+    /// assert!(!rect.has_intersection(FRect::new(5.0, 0.0, 5.0, 5.0)));
+    /// ```
+    pub fn has_intersection(&self, other: FRect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Calculates the intersection of two rectangles.
+    ///
+    /// Returns `None` if the two rectangles don't intersect.
+    ///
+    /// The bitwise AND operator `&` can also be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sdl2::rect::FRect;
+    /// let rect = FRect::new(0.0, 0.0, 5.0, 5.0);
+    /// assert_eq!(rect.intersection(FRect::new(2.5, 2.0, 5.0, 5.0)),
+    ///            Some(FRect::new(2.5, 2.0, 2.5, 3.0)));
+    /// assert_eq!(rect.intersection(FRect::new(5.0, 0.0, 5.0, 5.0)), None);
+    /// ```
+    pub fn intersection(&self, other: FRect) -> Option<FRect> {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        if self.is_empty() || other.is_empty() || right <= left || bottom <= top {
+            None
+        } else {
+            Some(FRect::new(left, top, right - left, bottom - top))
+        }
+    }
+
+    /// Calculates the union of two rectangles (i.e. the smallest rectangle
+    /// that contains both).
+    ///
+    /// Empty rectangles are ignored. The bitwise OR operator `|` can also be
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sdl2::rect::FRect;
+    /// let rect = FRect::new(0.0, 0.0, 5.0, 5.0);
+    /// assert_eq!(rect.union(FRect::new(2.5, 2.5, 5.0, 5.0)),
+    ///            FRect::new(0.0, 0.0, 7.5, 7.5));
+    /// ```
+    pub fn union(&self, other: FRect) -> FRect {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return other;
+        }
+
+        let left = self.left().min(other.left());
+        let top = self.top().min(other.top());
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+
+        FRect::new(left, top, right - left, bottom - top)
+    }
+
+    /// Returns the underlying C rect.
+    pub fn raw(&self) -> *const sys::SDL_FRect {
+        &self.raw
+    }
+
+    pub fn raw_mut(&mut self) -> *mut sys::SDL_FRect {
+        self.raw() as *mut _
+    }
+
+    pub fn raw_slice(slice: &[FRect]) -> *const sys::SDL_FRect {
+        slice.as_ptr() as *const sys::SDL_FRect
+    }
+
+    pub fn from_ll(raw: sys::SDL_FRect) -> FRect {
+        FRect { raw }
+    }
+}
+
+impl Deref for FRect {
+    type Target = sys::SDL_FRect;
+
+    fn deref(&self) -> &sys::SDL_FRect {
+        &self.raw
+    }
+}
+
+impl DerefMut for FRect {
+    fn deref_mut(&mut self) -> &mut sys::SDL_FRect {
+        &mut self.raw
+    }
+}
+
+impl Into<sys::SDL_FRect> for FRect {
+    fn into(self) -> sys::SDL_FRect {
+        self.raw
+    }
+}
+
+impl Into<(f32, f32, f32, f32)> for FRect {
+    fn into(self) -> (f32, f32, f32, f32) {
+        (self.raw.x, self.raw.y, self.raw.w, self.raw.h)
+    }
+}
+
+impl From<sys::SDL_FRect> for FRect {
+    fn from(raw: sys::SDL_FRect) -> FRect {
+        FRect { raw }
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for FRect {
+    fn from((x, y, width, height): (f32, f32, f32, f32)) -> FRect {
+        FRect::new(x, y, width, height)
+    }
+}
+
+impl From<Rect> for FRect {
+    fn from(rect: Rect) -> FRect {
+        FRect::new(rect.x() as f32, rect.y() as f32, rect.width() as f32, rect.height() as f32)
+    }
+}
+
+/// Converts to an integer rectangle by rounding each component to the
+/// nearest integer. The result is clamped like any other `Rect`.
+impl From<FRect> for Rect {
+    fn from(rect: FRect) -> Rect {
+        let width = rect.width().round().max(0.0);
+        let height = rect.height().round().max(0.0);
+        Rect::new(rect.x().round() as i32, rect.y().round() as i32, width as u32, height as u32)
+    }
+}
+
+impl AsRef<sys::SDL_FRect> for FRect {
+    fn as_ref(&self) -> &sys::SDL_FRect {
+        &self.raw
+    }
+}
+
+impl AsMut<sys::SDL_FRect> for FRect {
+    fn as_mut(&mut self) -> &mut sys::SDL_FRect {
+        &mut self.raw
+    }
+}
+
+// Intersection
+impl BitAnd<FRect> for FRect {
+    type Output = Option<FRect>;
+    fn bitand(self, rhs: FRect) -> Option<FRect> { self.intersection(rhs) }
+}
+
+// Union
+impl BitOr<FRect> for FRect {
+    type Output = FRect;
+    fn bitor(self, rhs: FRect) -> FRect { self.union(rhs) }
+}
+
+/// A point with floating point coordinates, as used by the `_f` rendering functions.
+///
+/// Points support vector arithmetic through `+`, `-`, unary `-`, and `*`/`/`
+/// by an `f32`.
+#[derive(Copy, Clone)]
+pub struct FPoint {
+    raw: sys::SDL_FPoint
+}
+
+impl ::std::fmt::Debug for FPoint {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+        return write!(fmt, "FPoint {{ x: {}, y: {} }}", self.raw.x, self.raw.y);
+    }
+}
+
+impl PartialEq for FPoint {
+    fn eq(&self, other: &FPoint) -> bool {
+        self.raw.x == other.raw.x && self.raw.y == other.raw.y
+    }
+}
+
+impl Deref for FPoint {
+    type Target = sys::SDL_FPoint;
+
+    fn deref(&self) -> &sys::SDL_FPoint {
+        &self.raw
+    }
+}
+
+impl DerefMut for FPoint {
+    fn deref_mut(&mut self) -> &mut sys::SDL_FPoint {
+        &mut self.raw
+    }
+}
+
+impl AsRef<sys::SDL_FPoint> for FPoint {
+    fn as_ref(&self) -> &sys::SDL_FPoint {
+        &self.raw
+    }
+}
+
+impl AsMut<sys::SDL_FPoint> for FPoint {
+    fn as_mut(&mut self) -> &mut sys::SDL_FPoint {
+        &mut self.raw
+    }
+}
+
+impl From<sys::SDL_FPoint> for FPoint {
+    fn from(raw: sys::SDL_FPoint) -> FPoint {
+        FPoint { raw }
+    }
+}
+
+impl From<(f32, f32)> for FPoint {
+    fn from((x, y): (f32, f32)) -> FPoint {
+        FPoint::new(x, y)
+    }
+}
+
+impl From<Point> for FPoint {
+    fn from(point: Point) -> FPoint {
+        FPoint::new(point.x() as f32, point.y() as f32)
+    }
+}
+
+/// Converts to an integer point by rounding each coordinate to the nearest
+/// integer.
+impl From<FPoint> for Point {
+    fn from(point: FPoint) -> Point {
+        Point::new(point.x().round() as i32, point.y().round() as i32)
+    }
+}
+
+impl Into<sys::SDL_FPoint> for FPoint {
+    fn into(self) -> sys::SDL_FPoint {
+        self.raw
+    }
+}
+
+impl Into<(f32, f32)> for FPoint {
+    fn into(self) -> (f32, f32) {
+        (self.x(), self.y())
+    }
+}
+
+impl FPoint {
+    /// Creates a new point from the given coordinates.
+    pub fn new(x: f32, y: f32) -> FPoint {
+        FPoint {
+            raw: sys::SDL_FPoint { x, y }
+        }
+    }
+
+    pub fn from_ll(raw: sys::SDL_FPoint) -> FPoint {
+        FPoint { raw }
+    }
+
+    pub fn raw_slice(slice: &[FPoint]) -> *const sys::SDL_FPoint {
+        slice.as_ptr() as *const sys::SDL_FPoint
+    }
+
+    pub fn raw(&self) -> *const sys::SDL_FPoint {
+        &self.raw
+    }
+
+    /// Returns a new point by shifting this point's coordinates by the given
+    /// x and y values.
+    pub fn offset(self, x: f32, y: f32) -> FPoint {
+        FPoint::new(self.raw.x + x, self.raw.y + y)
+    }
+
+    /// Returns a new point by multiplying this point's coordinates by the
+    /// given scale factor.
+    pub fn scale(self, f: f32) -> FPoint {
+        FPoint::new(self.raw.x * f, self.raw.y * f)
+    }
+
+    /// Returns the x-coordinate of this point.
+    pub fn x(self) -> f32 {
+        self.raw.x
+    }
+
+    /// Returns the y-coordinate of this point.
+    pub fn y(self) -> f32 {
+        self.raw.y
+    }
+}
+
+impl Add for FPoint {
+    type Output = FPoint;
+
+    fn add(self, rhs: FPoint) -> FPoint {
+        self.offset(rhs.x(), rhs.y())
+    }
+}
+
+impl AddAssign for FPoint {
+    fn add_assign(&mut self, rhs: FPoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for FPoint {
+    type Output = FPoint;
+
+    fn neg(self) -> FPoint {
+        FPoint::new(-self.x(), -self.y())
+    }
+}
+
+impl Sub for FPoint {
+    type Output = FPoint;
+
+    fn sub(self, rhs: FPoint) -> FPoint {
+        self.offset(-rhs.x(), -rhs.y())
+    }
+}
+
+impl SubAssign for FPoint {
+    fn sub_assign(&mut self, rhs: FPoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f32> for FPoint {
+    type Output = FPoint;
+
+    fn mul(self, rhs: f32) -> FPoint {
+        self.scale(rhs)
+    }
+}
+
+impl MulAssign<f32> for FPoint {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f32> for FPoint {
+    type Output = FPoint;
+
+    fn div(self, rhs: f32) -> FPoint {
+        FPoint::new(self.x() / rhs, self.y() / rhs)
+    }
+}
+
+impl DivAssign<f32> for FPoint {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{Rect, Point, max_int_value, min_int_value};
+    use super::{Rect, Point, FRect, FPoint, max_int_value, min_int_value};
 
     /// Used to compare "literal" (unclamped) rect values.
     fn tuple(x: i32, y: i32, w: u32, h: u32) -> (i32, i32, u32, u32) {
@@ -1130,6 +1716,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn frect_intersection_and_union() {
+        let a = FRect::new(0.0, 0.0, 4.0, 4.0);
+        let b = FRect::new(2.0, 1.0, 4.0, 2.0);
+        assert_eq!(a & b, Some(FRect::new(2.0, 1.0, 2.0, 2.0)));
+        assert_eq!(a | b, FRect::new(0.0, 0.0, 6.0, 4.0));
+        assert_eq!(a & FRect::new(4.0, 0.0, 1.0, 1.0), None);
+        assert_eq!(a | FRect::new(9.0, 9.0, 0.0, 0.0), a);
+    }
+
+    #[test]
+    fn frect_rect_conversions() {
+        assert_eq!(FRect::from(Rect::new(-1, 2, 3, 4)), FRect::new(-1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Rect::from(FRect::new(-1.4, 1.6, 2.5, 0.2)), Rect::new(-1, 2, 3, 1));
+        assert_eq!(Point::from(FPoint::new(1.5, -2.6)), Point::new(2, -3));
+        assert_eq!(FPoint::from(Point::new(1, -2)), FPoint::new(1.0, -2.0));
+    }
+
     #[test]
     fn point_div_assign () {
         let mut point = Point::new(-11, 5);
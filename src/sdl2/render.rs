@@ -40,15 +40,16 @@ use std::error::Error;
 use std::marker::PhantomData;
 use std::mem;
 use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr;
 use std::rc::Rc;
 use libc::{c_int, c_double};
-use crate::rect::Point;
+use crate::rect::{FPoint, FRect, Point};
 use crate::rect::Rect;
 use std::ffi::CStr;
 use std::convert::TryFrom;
-use crate::common::{validate_int, IntegerOrSdlError};
-use std::mem::{transmute, MaybeUninit};
+use crate::common::{missing_function_error, validate_int, IntegerOrSdlError};
+use std::mem::transmute;
 use libc::c_void;
 
 use crate::sys;
@@ -64,6 +65,8 @@ pub struct SdlError(String);
 pub enum TargetRenderError {
     SdlError(SdlError),
     NotSupported,
+    /// The texture wasn't created with `TextureAccess::Target`.
+    InvalidTextureAccess(TextureAccess),
 }
 
 impl fmt::Display for SdlError {
@@ -86,6 +89,9 @@ impl fmt::Display for TargetRenderError {
         match *self {
             SdlError(ref e) => e.fmt(f),
             NotSupported => write!(f, "The renderer does not support the use of render targets"),
+            InvalidTextureAccess(access) => {
+                write!(f, "The texture was created with {:?} access instead of Target", access)
+            }
         }
     }
 }
@@ -96,6 +102,7 @@ impl Error for TargetRenderError {
         match *self {
             SdlError(ref e) => e.description(),
             NotSupported => "The renderer does not support the use of render targets",
+            InvalidTextureAccess(_) => "The texture was not created with Target access",
         }
     }
 }
@@ -127,10 +134,15 @@ impl TryFrom<u32> for TextureAccess {
 /// or the current render context.
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct RendererInfo {
+    /// The name of the render driver, e.g. `"opengl"`, `"direct3d"` or `"metal"`.
     pub name: &'static str,
+    /// A mask of `SDL_RendererFlags` supported by the driver.
     pub flags: u32,
+    /// The texture formats supported by the driver.
     pub texture_formats: Vec<PixelFormatEnum>,
+    /// The maximum texture width, or 0 if there is no limit.
     pub max_texture_width: u32,
+    /// The maximum texture height, or 0 if there is no limit.
     pub max_texture_height: u32,
 }
 
@@ -147,20 +159,278 @@ pub enum BlendMode {
 impl TryFrom<u32> for BlendMode {
     type Error = ();
 
+    /// Fails for custom blend modes, which don't have a `BlendMode` variant.
     fn try_from(n: u32) -> Result<Self, Self::Error> {
         use self::BlendMode::*;
         use crate::sys::SDL_BlendMode::*;
 
-        Ok(match unsafe { transmute(n) } {
-               SDL_BLENDMODE_NONE => None,
-               SDL_BLENDMODE_BLEND => Blend,
-               SDL_BLENDMODE_ADD => Add,
-               SDL_BLENDMODE_MOD => Mod,
-               SDL_BLENDMODE_INVALID => Invalid,
+        Ok(match n {
+               n if n == SDL_BLENDMODE_NONE as u32 => None,
+               n if n == SDL_BLENDMODE_BLEND as u32 => Blend,
+               n if n == SDL_BLENDMODE_ADD as u32 => Add,
+               n if n == SDL_BLENDMODE_MOD as u32 => Mod,
+               n if n == SDL_BLENDMODE_INVALID as u32 => Invalid,
+               _ => return Err(()),
            })
     }
 }
 
+/// The factor a color or alpha component is multiplied with in a `CustomBlendMode`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BlendFactor {
+    /// 0, 0, 0, 0
+    Zero,
+    /// 1, 1, 1, 1
+    One,
+    /// srcR, srcG, srcB, srcA
+    SrcColor,
+    /// 1-srcR, 1-srcG, 1-srcB, 1-srcA
+    OneMinusSrcColor,
+    /// srcA, srcA, srcA, srcA
+    SrcAlpha,
+    /// 1-srcA, 1-srcA, 1-srcA, 1-srcA
+    OneMinusSrcAlpha,
+    /// dstR, dstG, dstB, dstA
+    DstColor,
+    /// 1-dstR, 1-dstG, 1-dstB, 1-dstA
+    OneMinusDstColor,
+    /// dstA, dstA, dstA, dstA
+    DstAlpha,
+    /// 1-dstA, 1-dstA, 1-dstA, 1-dstA
+    OneMinusDstAlpha,
+}
+
+impl BlendFactor {
+    fn to_ll(self) -> sys::SDL_BlendFactor {
+        use crate::sys::SDL_BlendFactor::*;
+        match self {
+            BlendFactor::Zero => SDL_BLENDFACTOR_ZERO,
+            BlendFactor::One => SDL_BLENDFACTOR_ONE,
+            BlendFactor::SrcColor => SDL_BLENDFACTOR_SRC_COLOR,
+            BlendFactor::OneMinusSrcColor => SDL_BLENDFACTOR_ONE_MINUS_SRC_COLOR,
+            BlendFactor::SrcAlpha => SDL_BLENDFACTOR_SRC_ALPHA,
+            BlendFactor::OneMinusSrcAlpha => SDL_BLENDFACTOR_ONE_MINUS_SRC_ALPHA,
+            BlendFactor::DstColor => SDL_BLENDFACTOR_DST_COLOR,
+            BlendFactor::OneMinusDstColor => SDL_BLENDFACTOR_ONE_MINUS_DST_COLOR,
+            BlendFactor::DstAlpha => SDL_BLENDFACTOR_DST_ALPHA,
+            BlendFactor::OneMinusDstAlpha => SDL_BLENDFACTOR_ONE_MINUS_DST_ALPHA,
+        }
+    }
+}
+
+/// The operation combining the source and destination components in a `CustomBlendMode`.
+///
+/// Only `Add` is supported by all renderers.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BlendOperation {
+    /// dst + src
+    Add,
+    /// dst - src
+    Subtract,
+    /// src - dst
+    RevSubtract,
+    /// min(dst, src)
+    Minimum,
+    /// max(dst, src)
+    Maximum,
+}
+
+impl BlendOperation {
+    fn to_ll(self) -> sys::SDL_BlendOperation {
+        use crate::sys::SDL_BlendOperation::*;
+        match self {
+            BlendOperation::Add => SDL_BLENDOPERATION_ADD,
+            BlendOperation::Subtract => SDL_BLENDOPERATION_SUBTRACT,
+            BlendOperation::RevSubtract => SDL_BLENDOPERATION_REV_SUBTRACT,
+            BlendOperation::Minimum => SDL_BLENDOPERATION_MINIMUM,
+            BlendOperation::Maximum => SDL_BLENDOPERATION_MAXIMUM,
+        }
+    }
+}
+
+/// A blend mode built from custom factors and operations, set with
+/// `Canvas::set_custom_blend_mode` or `Texture::set_custom_blend_mode`.
+///
+/// The resulting colors are:
+///
+/// ```text
+/// dstRGB = dstRGB * dst_color_factor color_operation srcRGB * src_color_factor
+/// dstA = dstA * dst_alpha_factor alpha_operation srcA * src_alpha_factor
+/// ```
+///
+/// Not every renderer supports every custom blend mode, setting an unsupported one returns
+/// an error.
+///
+/// ```no_run
+/// use sdl2::render::{BlendFactor, BlendOperation, CustomBlendMode};
+///
+/// // Additive blending for premultiplied alpha.
+/// let glow = CustomBlendMode::new(BlendFactor::One, BlendFactor::One, BlendOperation::Add,
+///                                 BlendFactor::Zero, BlendFactor::One, BlendOperation::Add);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CustomBlendMode {
+    raw: u32,
+}
+
+impl CustomBlendMode {
+    pub fn new(src_color_factor: BlendFactor,
+               dst_color_factor: BlendFactor,
+               color_operation: BlendOperation,
+               src_alpha_factor: BlendFactor,
+               dst_alpha_factor: BlendFactor,
+               alpha_operation: BlendOperation)
+               -> CustomBlendMode {
+        let raw = unsafe {
+            ll::SDL_ComposeCustomBlendMode(src_color_factor.to_ll(),
+                                           dst_color_factor.to_ll(),
+                                           color_operation.to_ll(),
+                                           src_alpha_factor.to_ll(),
+                                           dst_alpha_factor.to_ll(),
+                                           alpha_operation.to_ll())
+        };
+        CustomBlendMode { raw }
+    }
+}
+
+impl From<BlendMode> for CustomBlendMode {
+    fn from(blend: BlendMode) -> CustomBlendMode {
+        CustomBlendMode { raw: blend as u32 }
+    }
+}
+
+/// Declarations taking and returning blend modes as `u32`, as composed blend modes aren't
+/// valid values of the `SDL_BlendMode` enum.
+mod ll {
+    use libc::{c_double, c_int};
+    use crate::sys;
+
+    extern "C" {
+        pub fn SDL_ComposeCustomBlendMode(srcColorFactor: sys::SDL_BlendFactor,
+                                          dstColorFactor: sys::SDL_BlendFactor,
+                                          colorOperation: sys::SDL_BlendOperation,
+                                          srcAlphaFactor: sys::SDL_BlendFactor,
+                                          dstAlphaFactor: sys::SDL_BlendFactor,
+                                          alphaOperation: sys::SDL_BlendOperation)
+                                          -> u32;
+        pub fn SDL_SetRenderDrawBlendMode(renderer: *mut sys::SDL_Renderer, blendMode: u32) -> c_int;
+        pub fn SDL_SetTextureBlendMode(texture: *mut sys::SDL_Texture, blendMode: u32) -> c_int;
+        pub fn SDL_GetRenderDrawBlendMode(renderer: *mut sys::SDL_Renderer, blendMode: *mut u32) -> c_int;
+        pub fn SDL_GetTextureBlendMode(texture: *mut sys::SDL_Texture, blendMode: *mut u32) -> c_int;
+
+        // `sys::SDL_RendererFlip` can't hold both flips at once.
+        pub fn SDL_RenderCopyEx(renderer: *mut sys::SDL_Renderer,
+                                texture: *mut sys::SDL_Texture,
+                                srcrect: *const sys::SDL_Rect,
+                                dstrect: *const sys::SDL_Rect,
+                                angle: c_double,
+                                center: *const sys::SDL_Point,
+                                flip: u32)
+                                -> c_int;
+        pub fn SDL_RenderCopyExF(renderer: *mut sys::SDL_Renderer,
+                                 texture: *mut sys::SDL_Texture,
+                                 srcrect: *const sys::SDL_Rect,
+                                 dstrect: *const sys::SDL_FRect,
+                                 angle: c_double,
+                                 center: *const sys::SDL_FPoint,
+                                 flip: u32)
+                                 -> c_int;
+    }
+
+    /// `SDL_Vertex` from SDL 2.0.18.
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct SDL_Vertex {
+        pub position: sys::SDL_FPoint,
+        pub color: sys::SDL_Color,
+        pub tex_coord: sys::SDL_FPoint,
+    }
+
+    // Not in the SDL 2.0.10 bindings; looked up at runtime.
+    optional_sdl_fns! {
+        pub fn SDL_RenderGeometry(renderer: *mut sys::SDL_Renderer,
+                                  texture: *mut sys::SDL_Texture,
+                                  vertices: *const SDL_Vertex,
+                                  num_vertices: c_int,
+                                  indices: *const c_int,
+                                  num_indices: c_int)
+                                  -> c_int;
+    }
+}
+
+/// A vertex of a triangle drawn by `Canvas::render_geometry`, laid out like `SDL_Vertex`.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct Vertex {
+    raw: ll::SDL_Vertex,
+}
+
+impl Vertex {
+    /// Creates a new vertex.
+    ///
+    /// `position` is in render coordinates, and `tex_coord` is normalized, i.e. `(0.0, 0.0)`
+    /// is the top-left corner of the texture and `(1.0, 1.0)` its bottom-right corner.
+    pub fn new(position: (f32, f32), color: pixels::Color, tex_coord: (f32, f32)) -> Vertex {
+        Vertex {
+            raw: ll::SDL_Vertex {
+                position: sys::SDL_FPoint { x: position.0, y: position.1 },
+                color: color.into(),
+                tex_coord: sys::SDL_FPoint { x: tex_coord.0, y: tex_coord.1 },
+            }
+        }
+    }
+
+    /// Returns the position of the vertex, in render coordinates.
+    pub fn position(&self) -> (f32, f32) {
+        (self.raw.position.x, self.raw.position.y)
+    }
+
+    /// Returns the color of the vertex.
+    pub fn color(&self) -> pixels::Color {
+        pixels::Color::from(self.raw.color)
+    }
+
+    /// Returns the normalized texture coordinates of the vertex.
+    pub fn tex_coord(&self) -> (f32, f32) {
+        (self.raw.tex_coord.x, self.raw.tex_coord.y)
+    }
+
+    fn raw_slice(slice: &[Vertex]) -> *const ll::SDL_Vertex {
+        slice.as_ptr() as *const ll::SDL_Vertex
+    }
+}
+
+impl ::std::fmt::Debug for Vertex {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+        fmt.debug_struct("Vertex")
+            .field("position", &self.position())
+            .field("color", &self.color())
+            .field("tex_coord", &self.tex_coord())
+            .finish()
+    }
+}
+
+impl PartialEq for Vertex {
+    fn eq(&self, other: &Vertex) -> bool {
+        self.position() == other.position()
+            && self.color() == other.color()
+            && self.tex_coord() == other.tex_coord()
+    }
+}
+
+/// Combines the flip flags of `copy_ex` into an `SDL_RendererFlip` mask.
+fn to_ll_flip(flip_horizontal: bool, flip_vertical: bool) -> u32 {
+    use crate::sys::SDL_RendererFlip::*;
+    let mut flip = SDL_FLIP_NONE as u32;
+    if flip_horizontal {
+        flip |= SDL_FLIP_HORIZONTAL as u32;
+    }
+    if flip_vertical {
+        flip |= SDL_FLIP_VERTICAL as u32;
+    }
+    flip
+}
+
 impl RendererInfo {
     pub unsafe fn from_ll(info: &sys::SDL_RendererInfo) -> RendererInfo {
         let texture_formats: Vec<PixelFormatEnum> =
@@ -202,7 +472,8 @@ impl<T> Drop for RendererContext<T> {
 }
 
 impl<T> RendererContext<T> {
-    /// Gets information about the rendering context.
+    /// Gets information about the rendering context, such as the name of the
+    /// render driver that was actually selected.
     pub fn info(&self) -> RendererInfo {
         let mut renderer_info_raw = mem::MaybeUninit::uninit();
         let result = unsafe { sys::SDL_GetRendererInfo(self.raw, renderer_info_raw.as_mut_ptr()) != 0 };
@@ -243,6 +514,13 @@ impl<T> RendererContext<T> {
     }
 }
 
+fn check_target_access(raw_texture: *mut sys::SDL_Texture) -> Result<(), TargetRenderError> {
+    match (InternalTexture { raw: raw_texture }).query().access {
+        TextureAccess::Target => Ok(()),
+        access => Err(TargetRenderError::InvalidTextureAccess(access)),
+    }
+}
+
 impl<T: RenderTarget> Deref for Canvas<T> {
     type Target = RendererContext<T::Context>;
 
@@ -334,6 +612,25 @@ impl<'s> Canvas<Surface<'s>> {
     ///
     /// This method should only fail if SDL2 is not built with rendering
     /// support, or there's an out-of-memory error.
+    ///
+    /// Drawing goes straight into the surface, so no window or display server is
+    /// needed. Call `into_surface` to get the surface back once you're done.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use sdl2::pixels::{Color, PixelFormatEnum};
+    /// use sdl2::render::Canvas;
+    /// use sdl2::surface::Surface;
+    ///
+    /// let surface = Surface::new(64, 64, PixelFormatEnum::RGBA32).unwrap();
+    /// let mut canvas = Canvas::from_surface(surface).unwrap();
+    /// canvas.set_draw_color(Color::RGB(255, 0, 0));
+    /// canvas.clear();
+    ///
+    /// let surface = canvas.into_surface();
+    /// surface.with_lock(|pixels| assert_eq!(&pixels[0..4], &[255, 0, 0, 255]));
+    /// ```
     pub fn from_surface(surface: surface::Surface<'s>) -> Result<Self, String> {
         let raw_renderer = unsafe { sys::SDL_CreateSoftwareRenderer(surface.raw()) };
         if !raw_renderer.is_null() {
@@ -468,11 +765,10 @@ impl<T: RenderTarget> Canvas<T> {
     /// * returns `TargetRenderError::NotSupported`
     /// if the renderer does not support the use of render targets
     /// * returns `TargetRenderError::SdlError` if SDL2 returned with an error code.
+    /// * returns `TargetRenderError::InvalidTextureAccess` if the texture wasn't created with
+    /// the texture access `sdl2::render::TextureAccess::Target`.
     ///
-    /// The texture *must* be created with the texture access:
-    /// `sdl2::render::TextureAccess::Target`.
-    /// Using a texture which was not created with the texture access `Target` is undefined
-    /// behavior.
+    /// The previous render target is restored once the closure returns, even if it panics.
     ///
     /// # Examples
     ///
@@ -501,18 +797,34 @@ impl<T: RenderTarget> Canvas<T> {
     pub fn with_texture_canvas<F>(&mut self, texture: &mut Texture, f: F)
         -> Result<(), TargetRenderError> where for<'r> F: FnOnce(&'r mut Canvas<T>,) {
         if self.render_target_supported() {
-            let target = unsafe { self.get_raw_target() };
-            unsafe { self.set_raw_target(texture.raw) }
-                .map_err(TargetRenderError::SdlError)?;
-            f(self);
-            unsafe { self.set_raw_target(target) }
-                .map_err(TargetRenderError::SdlError)?;
-            Ok(())
+            check_target_access(texture.raw)?;
+            self.with_restored_target(|canvas| {
+                unsafe { canvas.set_raw_target(texture.raw) }
+                    .map_err(TargetRenderError::SdlError)?;
+                f(canvas);
+                Ok(())
+            })
         } else {
             Err(TargetRenderError::NotSupported)
         }
     }
 
+    /// Runs `f`, then restores the current render target, even if `f` panics.
+    fn with_restored_target<F>(&mut self, f: F) -> Result<(), TargetRenderError>
+        where F: FnOnce(&mut Canvas<T>) -> Result<(), TargetRenderError> {
+        let target = unsafe { self.get_raw_target() };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(self)));
+        // reset the target to its source
+        let reset = unsafe { self.set_raw_target(target) };
+        match result {
+            Ok(result) => {
+                result?;
+                reset.map_err(TargetRenderError::SdlError)
+            }
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+
     /// Same as `with_texture_canvas`, but allows to change multiple `Texture`s at once with the
     /// least amount of overhead. It means that between every iteration the Target is not reset to
     /// the source, and that the fact that the Canvas supports render target isn't checked every
@@ -574,16 +886,15 @@ impl<T: RenderTarget> Canvas<T> {
         -> Result<(), TargetRenderError>
         where for<'r> F: FnMut(&'r mut Canvas<T>, &U), I: Iterator<Item=&'s (&'a mut Texture<'t>, U)> {
         if self.render_target_supported() {
-            let target = unsafe { self.get_raw_target() };
-            for &(ref texture, ref user_context) in textures {
-                unsafe { self.set_raw_target(texture.raw) }
-                    .map_err(TargetRenderError::SdlError)?;
-                f(self, user_context);
-            }
-            // reset the target to its source
-            unsafe { self.set_raw_target(target) }
-                .map_err(TargetRenderError::SdlError)?;
-            Ok(())
+            self.with_restored_target(|canvas| {
+                for &(ref texture, ref user_context) in textures {
+                    check_target_access(texture.raw)?;
+                    unsafe { canvas.set_raw_target(texture.raw) }
+                        .map_err(TargetRenderError::SdlError)?;
+                    f(canvas, user_context);
+                }
+                Ok(())
+            })
         } else {
             Err(TargetRenderError::NotSupported)
         }
@@ -594,15 +905,15 @@ impl<T: RenderTarget> Canvas<T> {
         -> Result<(), TargetRenderError>
         where for<'r> F: FnMut(&'r mut Canvas<T>, &U), I: Iterator<Item=&'s (&'a mut Texture, U)> {
         if self.render_target_supported() {
-            for &(ref texture, ref user_context) in textures {
-                unsafe { self.set_raw_target(texture.raw) }
-                    .map_err(|e| TargetRenderError::SdlError(e))?;
-                f(self, &user_context);
-            }
-            // reset the target to its source
-            unsafe { self.set_raw_target(ptr::null_mut()) }
-                .map_err(|e| TargetRenderError::SdlError(e))?;
-            Ok(())
+            self.with_restored_target(|canvas| {
+                for &(ref texture, ref user_context) in textures {
+                    check_target_access(texture.raw)?;
+                    unsafe { canvas.set_raw_target(texture.raw) }
+                        .map_err(TargetRenderError::SdlError)?;
+                    f(canvas, user_context);
+                }
+                Ok(())
+            })
         } else {
             Err(TargetRenderError::NotSupported)
         }
@@ -651,6 +962,9 @@ impl CanvasBuilder {
     }
 
     /// Builds the renderer.
+    ///
+    /// Returns an `SdlError` if no rendering driver supports all of the
+    /// requested flags, or if the driver selected with `index` doesn't.
     pub fn build(self) -> Result<WindowCanvas, IntegerOrSdlError> {
         use crate::common::IntegerOrSdlError::*;
         let index = match self.index {
@@ -676,6 +990,9 @@ impl CanvasBuilder {
     /// If you desire the first rendering driver to support the flags provided,
     /// or if you're translating code from C which passes -1 for the index,
     /// **do not** invoke the `index` method.
+    ///
+    /// The available drivers can be listed with `sdl2::render::drivers()`, and
+    /// a driver can be looked up by name with `sdl2::render::driver_index()`.
     pub fn index(mut self, index: u32) -> CanvasBuilder {
         self.index = Some(index);
         self
@@ -895,6 +1212,38 @@ impl<T> TextureCreator<T> {
     }
 }
 
+/// Number of points drawn per call to SDL by `Canvas::draw_points_iter` and
+/// `Canvas::draw_lines_iter`.
+const DRAW_BATCH_SIZE: usize = 256;
+
+/// Splits `points` into batches of at most `DRAW_BATCH_SIZE` points that are drawn with
+/// `draw_lines`. Consecutive batches share their boundary point so that the lines stay
+/// connected.
+fn for_each_line_batch<P, I, F>(points: I, mut draw_lines: F) -> Result<(), String>
+    where P: Into<Point>,
+          I: IntoIterator<Item = P>,
+          F: FnMut(&[Point]) -> Result<(), String>
+{
+    let mut buffer = [Point::new(0, 0); DRAW_BATCH_SIZE];
+    let mut len = 0;
+    let mut drawn = false;
+    for point in points {
+        buffer[len] = point.into();
+        len += 1;
+        if len == DRAW_BATCH_SIZE {
+            draw_lines(&buffer[..])?;
+            buffer[0] = buffer[DRAW_BATCH_SIZE - 1];
+            len = 1;
+            drawn = true;
+        }
+    }
+    // After a batch, the only buffered point is the end of the last line.
+    if len > 1 || (len == 1 && !drawn) {
+        draw_lines(&buffer[..len])?;
+    }
+    Ok(())
+}
+
 /// Drawing methods
 impl<T: RenderTarget> Canvas<T> {
     pub fn raw(&self) -> *mut sys::SDL_Renderer {
@@ -935,16 +1284,29 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Sets a custom blend mode used for drawing operations (Fill and Line).
+    ///
+    /// Returns an error if the renderer doesn't support this blend mode.
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), String> {
+        let ret = unsafe { ll::SDL_SetRenderDrawBlendMode(self.context.raw, blend.raw) };
+        if ret != 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
     /// Gets the blend mode used for drawing operations.
+    ///
+    /// Returns `BlendMode::Invalid` if a custom blend mode is set.
     pub fn blend_mode(&self) -> BlendMode {
-        let mut blend: MaybeUninit<SDL_BlendMode> = mem::MaybeUninit::uninit();
-        let ret = unsafe { sys::SDL_GetRenderDrawBlendMode(self.context.raw, blend.as_mut_ptr()) };
+        let mut blend = 0;
+        let ret = unsafe { ll::SDL_GetRenderDrawBlendMode(self.context.raw, &mut blend) };
         // Should only fail on an invalid renderer
         if ret != 0 {
-            panic!(get_error())
+            panic!("{}", get_error())
         } else {
-            let blend = unsafe { blend.assume_init() };
-            BlendMode::try_from(blend as u32).unwrap()
+            BlendMode::try_from(blend).unwrap_or(BlendMode::Invalid)
         }
     }
 
@@ -967,7 +1329,10 @@ impl<T: RenderTarget> Canvas<T> {
         unsafe { sys::SDL_RenderPresent(self.context.raw) }
     }
 
-    /// Gets the output size of a rendering context.
+    /// Gets the output size of a rendering context, in pixels.
+    ///
+    /// On HiDPI displays this may be larger than the size of the window,
+    /// which is measured in screen coordinates.
     pub fn output_size(&self) -> Result<(u32, u32), String> {
         let mut width = 0;
         let mut height = 0;
@@ -983,6 +1348,9 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Sets a device independent resolution for rendering.
+    ///
+    /// The logical area is scaled to fit the output while keeping its aspect ratio. Use
+    /// `set_integer_scale` to restrict the scale to whole numbers.
     pub fn set_logical_size(&mut self, width: u32, height: u32) -> Result<(), IntegerOrSdlError> {
         use crate::common::IntegerOrSdlError::*;
         let width = validate_int(width, "width")?;
@@ -995,6 +1363,8 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Gets device independent resolution for rendering.
+    ///
+    /// Returns `(0, 0)` if no logical size has been set.
     pub fn logical_size(&self) -> (u32, u32) {
         let mut width = 0;
         let mut height = 0;
@@ -1004,7 +1374,26 @@ impl<T: RenderTarget> Canvas<T> {
         (width as u32, height as u32)
     }
 
+    /// Forces the scale used to fit the logical size set with `set_logical_size` to the
+    /// output to be an integer, for crisp upscaling of pixel art.
+    ///
+    /// The remaining space around the logical area is letterboxed. This has no effect
+    /// until a logical size is set.
+    pub fn set_integer_scale(&mut self, scale: bool) -> Result<(), String> {
+        let scale = if scale { sys::SDL_bool::SDL_TRUE } else { sys::SDL_bool::SDL_FALSE };
+        let ret = unsafe { sys::SDL_RenderSetIntegerScale(self.context.raw, scale) };
+        if ret != 0 { Err(get_error()) } else { Ok(()) }
+    }
+
+    /// Returns whether the scale used to fit the logical size is forced to be an integer.
+    pub fn integer_scale(&self) -> bool {
+        unsafe { sys::SDL_RenderGetIntegerScale(self.context.raw) == sys::SDL_bool::SDL_TRUE }
+    }
+
     /// Sets the drawing area for rendering on the current target.
+    ///
+    /// Drawing coordinates become relative to the top-left corner of the viewport. If the
+    /// rectangle is `None`, the viewport is reset to the whole target.
     pub fn set_viewport<R: Into<Option<Rect>>>(&mut self, rect: R) {
         let ptr = match rect.into() {
             Some(ref rect) => rect.raw(),
@@ -1044,6 +1433,10 @@ impl<T: RenderTarget> Canvas<T> {
     ///
     /// Returns `None` if clipping is disabled.
     pub fn clip_rect(&self) -> Option<Rect> {
+        if !self.is_clip_enabled() {
+            return None;
+        }
+
         let mut raw = mem::MaybeUninit::uninit();
         unsafe { sys::SDL_RenderGetClipRect(self.context.raw, raw.as_mut_ptr()) };
         let raw = unsafe { raw.assume_init() };
@@ -1054,6 +1447,11 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Returns whether clipping is enabled on the current target.
+    pub fn is_clip_enabled(&self) -> bool {
+        unsafe { sys::SDL_RenderIsClipEnabled(self.context.raw) == sys::SDL_bool::SDL_TRUE }
+    }
+
     /// Sets the drawing scale for rendering on the current target.
     pub fn set_scale(&mut self, scale_x: f32, scale_y: f32) -> Result<(), String> {
         let ret = unsafe { sys::SDL_RenderSetScale(self.context.raw, scale_x, scale_y) };
@@ -1097,6 +1495,31 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Draws multiple points on the current rendering target, without collecting them first.
+    ///
+    /// The points are buffered on the stack and drawn in batches with `draw_points`, so no
+    /// allocation is made.
+    /// Errors if drawing fails for any reason (e.g. driver failure)
+    pub fn draw_points_iter<P, I>(&mut self, points: I) -> Result<(), String>
+        where P: Into<Point>,
+              I: IntoIterator<Item = P>
+    {
+        let mut buffer = [Point::new(0, 0); DRAW_BATCH_SIZE];
+        let mut len = 0;
+        for point in points {
+            buffer[len] = point.into();
+            len += 1;
+            if len == DRAW_BATCH_SIZE {
+                self.draw_points(&buffer[..])?;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            self.draw_points(&buffer[..len])?;
+        }
+        Ok(())
+    }
+
     /// Draws a line on the current rendering target.
     /// Errors if drawing fails for any reason (e.g. driver failure)
     pub fn draw_line<P1: Into<Point>, P2: Into<Point>>(&mut self,
@@ -1131,6 +1554,19 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Draws a series of connected lines on the current rendering target, without collecting
+    /// the points first.
+    ///
+    /// The points are buffered on the stack and drawn in batches with `draw_lines`;
+    /// consecutive batches share their boundary point so that the lines stay connected.
+    /// Errors if drawing fails for any reason (e.g. driver failure)
+    pub fn draw_lines_iter<P, I>(&mut self, points: I) -> Result<(), String>
+        where P: Into<Point>,
+              I: IntoIterator<Item = P>
+    {
+        for_each_line_batch(points, |batch| self.draw_lines(batch))
+    }
+
     /// Draws a rectangle on the current rendering target.
     /// Errors if drawing fails for any reason (e.g. driver failure)
     pub fn draw_rect(&mut self, rect: Rect) -> Result<(), String> {
@@ -1192,6 +1628,41 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Renders a list of triangles, optionally textured, with per-vertex colors.
+    ///
+    /// Without `indices`, every three consecutive vertices form a triangle. With `indices`,
+    /// every three consecutive indices into `vertices` do. The color and alpha modulation of
+    /// `texture` are ignored; the colors of the vertices are used instead.
+    ///
+    /// Errors if drawing fails, if an index is out of bounds, or if the linked SDL is older
+    /// than 2.0.18 or statically linked.
+    pub fn render_geometry(&mut self,
+                           texture: Option<&Texture>,
+                           vertices: &[Vertex],
+                           indices: Option<&[i32]>)
+                           -> Result<(), String> {
+        let texture = texture.map_or(ptr::null_mut(), |t| t.raw);
+        let (indices_ptr, num_indices) = match indices {
+            Some(indices) => (indices.as_ptr(), indices.len() as c_int),
+            None => (ptr::null(), 0),
+        };
+
+        let result = unsafe {
+            ll::SDL_RenderGeometry(self.context.raw,
+                                   texture,
+                                   Vertex::raw_slice(vertices),
+                                   vertices.len() as c_int,
+                                   indices_ptr,
+                                   num_indices)
+        };
+
+        match result {
+            Some(0) => Ok(()),
+            Some(_) => Err(get_error()),
+            None => Err(missing_function_error("SDL_RenderGeometry", "2.0.18")),
+        }
+    }
+
     /// Copies a portion of the texture to the current rendering target.
     ///
     /// * If `src` is `None`, the entire texture is copied.
@@ -1220,6 +1691,35 @@ impl<T: RenderTarget> Canvas<T> {
         if ret != 0 { Err(get_error()) } else { Ok(()) }
     }
 
+    /// Like `copy`, but with a floating point destination rectangle, for sub-pixel
+    /// positioning.
+    ///
+    /// * If `src` is `None`, the entire texture is copied.
+    /// * If `dst` is `None`, the texture will be stretched to fill the given
+    ///   rectangle.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    pub fn copy_f<R1, R2>(&mut self, texture: &Texture, src: R1, dst: R2) -> Result<(), String>
+        where R1: Into<Option<Rect>>,
+              R2: Into<Option<FRect>>
+    {
+        let ret = unsafe {
+            sys::SDL_RenderCopyF(self.context.raw,
+                                 texture.raw,
+                                 match src.into() {
+                                     Some(ref rect) => rect.raw(),
+                                     None => ptr::null(),
+                                 },
+                                 match dst.into() {
+                                     Some(ref rect) => rect.raw(),
+                                     None => ptr::null(),
+                                 })
+        };
+
+        if ret != 0 { Err(get_error()) } else { Ok(()) }
+    }
+
     /// Copies a portion of the texture to the current rendering target,
     /// optionally rotating it by angle around the given center and also
     /// flipping it top-bottom and/or left-right.
@@ -1246,19 +1746,10 @@ impl<T: RenderTarget> Canvas<T> {
               R2: Into<Option<Rect>>,
               P: Into<Option<Point>>
     {
-        use crate::sys::SDL_RendererFlip::*;
-        let flip = unsafe { match (flip_horizontal, flip_vertical) {
-            (false, false) => SDL_FLIP_NONE,
-            (true, false) => SDL_FLIP_HORIZONTAL,
-            (false, true) => SDL_FLIP_VERTICAL,
-            (true, true) => transmute::<u32, sys::SDL_RendererFlip>(
-                            transmute::<sys::SDL_RendererFlip, u32>(SDL_FLIP_HORIZONTAL) |
-                            transmute::<sys::SDL_RendererFlip, u32>(SDL_FLIP_VERTICAL)
-                            ),
-        }};
+        let flip = to_ll_flip(flip_horizontal, flip_vertical);
 
         let ret = unsafe {
-            sys::SDL_RenderCopyEx(self.context.raw,
+            ll::SDL_RenderCopyEx(self.context.raw,
                                  texture.raw,
                                  match src.into() {
                                      Some(ref rect) => rect.raw(),
@@ -1279,27 +1770,83 @@ impl<T: RenderTarget> Canvas<T> {
         if ret != 0 { Err(get_error()) } else { Ok(()) }
     }
 
+    /// Like `copy_ex`, but with a floating point destination rectangle and center, for
+    /// sub-pixel positioning.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// if the provided texture does not belong to the renderer,
+    /// or if the driver does not support RenderCopyEx.
+    pub fn copy_ex_f<R1, R2, P>(&mut self,
+                                texture: &Texture,
+                                src: R1,
+                                dst: R2,
+                                angle: f64,
+                                center: P,
+                                flip_horizontal: bool,
+                                flip_vertical: bool)
+                                -> Result<(), String>
+        where R1: Into<Option<Rect>>,
+              R2: Into<Option<FRect>>,
+              P: Into<Option<FPoint>>
+    {
+        let flip = to_ll_flip(flip_horizontal, flip_vertical);
+
+        let ret = unsafe {
+            ll::SDL_RenderCopyExF(self.context.raw,
+                                  texture.raw,
+                                  match src.into() {
+                                      Some(ref rect) => rect.raw(),
+                                      None => ptr::null(),
+                                  },
+                                  match dst.into() {
+                                      Some(ref rect) => rect.raw(),
+                                      None => ptr::null(),
+                                  },
+                                  angle as c_double,
+                                  match center.into() {
+                                      Some(ref point) => point.raw(),
+                                      None => ptr::null(),
+                                  },
+                                  flip)
+        };
+
+        if ret != 0 { Err(get_error()) } else { Ok(()) }
+    }
+
     /// Reads pixels from the current rendering target.
+    ///
+    /// `rect` is relative to the viewport, and `None` reads the whole viewport. The returned
+    /// buffer holds the pixels row by row without padding, converted to `format`.
+    ///
+    /// Returns an error for indexed and YUV formats, which can't be read back.
     /// # Remarks
     /// WARNING: This is a very slow operation, and should not be used frequently.
     pub fn read_pixels<R: Into<Option<Rect>>>(&self,
                                               rect: R,
                                               format: pixels::PixelFormatEnum)
                                               -> Result<Vec<u8>, String> {
+        use crate::pixels::PixelFormatEnum::*;
+        match format {
+            Unknown | Index1LSB | Index1MSB | Index4LSB | Index4MSB | Index8 |
+            YV12 | IYUV | YUY2 | UYVY | YVYU => {
+                return Err(format!("Can't read pixels in the {:?} format", format));
+            }
+            _ => {}
+        }
+
         unsafe {
             let rect = rect.into();
             let (actual_rect, w, h) = match rect {
                 Some(ref rect) => (rect.raw(), rect.width() as usize, rect.height() as usize),
                 None => {
-                    let (w, h) = self.output_size()?;
-                    (ptr::null(), w as usize, h as usize)
+                    let viewport = self.viewport();
+                    (ptr::null(), viewport.width() as usize, viewport.height() as usize)
                 }
             };
 
             let pitch = w * format.byte_size_per_pixel(); // calculated pitch
             let size = format.byte_size_of_pixels(w * h);
-            let mut pixels = Vec::with_capacity(size);
-            pixels.set_len(size);
+            let mut pixels = vec![0; size];
 
             // Pass the interior of `pixels: Vec<u8>` to SDL
             let ret = {
@@ -1442,14 +1989,34 @@ impl<T: RenderTarget> Canvas<T> {
     }
 }
 
+/// The attributes of a texture, as returned by `Texture::query`.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct TextureQuery {
+    /// The pixel format of the texture.
     pub format: pixels::PixelFormatEnum,
+    /// How the texture can be accessed, as given when creating it.
     pub access: TextureAccess,
+    /// The width of the texture, in pixels.
     pub width: u32,
+    /// The height of the texture, in pixels.
     pub height: u32,
 }
 
+/// A type that can be used to access the pixels of a locked texture with `Texture::with_lock_as`.
+///
+/// # Safety
+///
+/// Any bit pattern must be a valid value of the type, as it is read from and written to the
+/// texture buffer as is.
+pub unsafe trait TexturePixel: Copy {}
+
+unsafe impl TexturePixel for u8 {}
+unsafe impl TexturePixel for u16 {}
+unsafe impl TexturePixel for u32 {}
+unsafe impl TexturePixel for [u8; 2] {}
+unsafe impl TexturePixel for [u8; 3] {}
+unsafe impl TexturePixel for [u8; 4] {}
+
 /// A texture for a rendering context.
 ///
 /// Every Texture is owned by a `TextureCreator` or `Canvas` (the latter is only possible with the
@@ -1606,6 +2173,8 @@ pub enum UpdateTextureYUVError {
     WidthMustBeMultipleOfTwoForFormat(u32),
     HeightMustBeMultipleOfTwoForFormat(u32),
     RectNotInsideTexture(Rect),
+    /// The texture format isn't a planar YUV format, i.e. `IYUV` or `YV12`.
+    UnsupportedFormat(PixelFormatEnum),
     SdlError(String),
 }
 
@@ -1643,6 +2212,9 @@ impl fmt::Display for UpdateTextureYUVError {
                 write!(f, "Height must be multiple of two ({})", value)
             }
             RectNotInsideTexture(_) => write!(f, "Rect must be inside texture"),
+            UnsupportedFormat(format) => {
+                write!(f, "Texture format must be IYUV or YV12 ({:?})", format)
+            }
             SdlError(ref e) => write!(f, "SDL error: {}", e),
         }
     }
@@ -1660,6 +2232,7 @@ impl Error for UpdateTextureYUVError {
             WidthMustBeMultipleOfTwoForFormat(_) => "width must be multiple of two",
             HeightMustBeMultipleOfTwoForFormat(_) => "height must be multiple of two",
             RectNotInsideTexture(_) => "rect must be inside texture",
+            UnsupportedFormat(_) => "texture format must be IYUV or YV12",
             SdlError(ref e) => e,
         }
     }
@@ -1738,16 +2311,25 @@ impl InternalTexture {
         }
     }
 
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), String> {
+        let ret = unsafe { ll::SDL_SetTextureBlendMode(self.raw, blend.raw) };
+
+        if ret != 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn blend_mode(&self) -> BlendMode {
-        let mut blend: MaybeUninit<SDL_BlendMode> = mem::MaybeUninit::uninit();
-        let ret = unsafe { sys::SDL_GetTextureBlendMode(self.raw, blend.as_mut_ptr()) };
+        let mut blend = 0;
+        let ret = unsafe { ll::SDL_GetTextureBlendMode(self.raw, &mut blend) };
 
         // Should only fail on an invalid texture
         if ret != 0 {
-            panic!(get_error())
+            panic!("{}", get_error())
         } else {
-            let blend = unsafe { blend.assume_init() };
-            BlendMode::try_from(blend as u32).unwrap()
+            BlendMode::try_from(blend).unwrap_or(BlendMode::Invalid)
         }
     }
 
@@ -1824,6 +2406,13 @@ impl InternalTexture {
 
         let rect = rect.into();
 
+        // SDL_UpdateYUVTexture reads three planes, which only matches the planar YUV formats.
+        let tex_info = self.query();
+        match tex_info.format {
+            PixelFormatEnum::IYUV | PixelFormatEnum::YV12 => (),
+            format => return Err(UnsupportedFormat(format)),
+        }
+
         let rect_raw_ptr = match rect {
             Some(ref rect) => rect.raw(),
             None => ptr::null(),
@@ -1843,7 +2432,6 @@ impl InternalTexture {
 
         // If the destination rectangle lies outside the texture boundaries,
         // SDL_UpdateYUVTexture will write outside allocated texture memory.
-        let tex_info = self.query();
         if let Some(ref r) = rect {
             let tex_rect = Rect::new(0, 0, tex_info.width, tex_info.height);
             let inside = match r.intersection(tex_rect) {
@@ -1972,6 +2560,57 @@ impl InternalTexture {
         }
     }
 
+    /// Implements `Texture::with_lock_as`, on top of `with_lock`.
+    pub fn with_lock_as<T, F, R, R2>(&mut self, rect: R2, func: F) -> Result<R, String>
+        where T: TexturePixel,
+              F: FnOnce(&mut [T], usize) -> R,
+              R2: Into<Option<Rect>>
+    {
+        use crate::pixels::PixelFormatEnum::*;
+        let rect = rect.into();
+        let q = self.query();
+        if q.access != TextureAccess::Streaming {
+            return Err("the texture must have been created with streaming access".to_owned());
+        }
+        let pixel_size = mem::size_of::<T>();
+        match q.format {
+            Unknown | Index1LSB | Index1MSB | Index4LSB | Index4MSB | YV12 | IYUV =>
+                return Err(format!("pixels of format {:?} can't be accessed individually", q.format)),
+            format if format.byte_size_per_pixel() != pixel_size =>
+                return Err(format!("pixels of format {:?} are {} bytes long, not {}",
+                                   format, format.byte_size_per_pixel(), pixel_size)),
+            _ => {}
+        }
+
+        let (width, height) = match rect {
+            Some(rect) => (rect.width() as usize, rect.height() as usize),
+            None => (q.width as usize, q.height as usize),
+        };
+
+        self.with_lock(rect, |bytes, pitch| {
+            if pitch % pixel_size == 0 && bytes.as_ptr() as usize % mem::align_of::<T>() == 0 {
+                let pixels = unsafe {
+                    ::std::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, bytes.len() / pixel_size)
+                };
+                return func(pixels, pitch / pixel_size);
+            }
+
+            // Rows that don't start on a pixel boundary, such as RGB24 rows padded to 4 bytes,
+            // can't be viewed as a slice of `T`: hand out packed rows and copy them back.
+            let row_len = width * pixel_size;
+            // `TexturePixel` guarantees that any bit pattern, so zero too, is valid.
+            let mut pixels = vec![unsafe { mem::zeroed::<T>() }; width * height];
+            let result = func(&mut pixels, width);
+            if row_len > 0 {
+                for (y, row) in pixels.chunks(width).enumerate() {
+                    let row = unsafe { ::std::slice::from_raw_parts(row.as_ptr() as *const u8, row_len) };
+                    bytes[y * pitch..y * pitch + row_len].copy_from_slice(row);
+                }
+            }
+            result
+        })
+    }
+
     pub unsafe fn gl_bind_texture(&mut self) -> (f32, f32) {
         let mut texw = 0.0;
         let mut texh = 0.0;
@@ -2048,7 +2687,17 @@ impl<'r> Texture<'r> {
         InternalTexture{ raw: self.raw }.set_blend_mode(blend)
     }
 
+    /// Sets a custom blend mode used for texture copy operations.
+    ///
+    /// Returns an error if the renderer doesn't support this blend mode.
+    #[inline]
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), String> {
+        InternalTexture{ raw: self.raw }.set_custom_blend_mode(blend)
+    }
+
     /// Gets the blend mode used for texture copy operations.
+    ///
+    /// Returns `BlendMode::Invalid` if a custom blend mode is set.
     #[inline]
     pub fn blend_mode(&self) -> BlendMode {
         InternalTexture{ raw: self.raw }.blend_mode()
@@ -2071,6 +2720,10 @@ impl<'r> Texture<'r> {
     }
 
     /// Updates a rectangle within a planar YV12 or IYUV texture with new pixel data.
+    ///
+    /// The `y_plane` must hold `y_pitch` bytes for each row of `rect`, and the `u_plane` and
+    /// `v_plane` their pitch for each row of half of it. Returns an error for other texture
+    /// formats, or if the lengths of the planes don't match.
     #[inline]
     pub fn update_yuv<R>(&mut self,
                          rect: R,
@@ -2103,6 +2756,31 @@ impl<'r> Texture<'r> {
         InternalTexture { raw: self.raw }.with_lock(rect, func)
     }
 
+    /// Locks the texture for **write-only** pixel access, as typed pixels.
+    /// The texture must have been created with streaming access.
+    ///
+    /// `F` is a function that is passed the write-only texture buffer as a slice of `T`, and
+    /// the number of `T` per row of the slice, which may be larger than the width of the
+    /// locked area. For instance, use `u32` to write pixels of a `PixelFormatEnum::ARGB8888`
+    /// texture, or `[u8; 3]` for `PixelFormatEnum::RGB24`.
+    ///
+    /// The slice usually is the texture buffer itself, with `pitch / size_of::<T>()` elements
+    /// per row. When SDL pads rows to a size that isn't a multiple of the size of `T`, or the
+    /// buffer isn't aligned for `T`, `F` is given a buffer of packed rows instead, which is
+    /// copied to the texture once `F` returns.
+    ///
+    /// Returns an error if the texture wasn't created with streaming access, if the size of
+    /// `T` isn't the size of a pixel of the texture format, if the format is planar or indexed
+    /// under 8 bits, or if the texture can't be locked.
+    #[inline]
+    pub fn with_lock_as<T, F, R, R2>(&mut self, rect: R2, func: F) -> Result<R, String>
+        where T: TexturePixel,
+              F: FnOnce(&mut [T], usize) -> R,
+              R2: Into<Option<Rect>>
+    {
+        InternalTexture { raw: self.raw }.with_lock_as(rect, func)
+    }
+
     /// Binds an OpenGL/ES/ES2 texture to the current
     /// context for use with when rendering OpenGL primitives directly.
     #[inline]
@@ -2166,7 +2844,17 @@ impl<> Texture<> {
         InternalTexture{ raw: self.raw }.set_blend_mode(blend)
     }
 
+    /// Sets a custom blend mode used for texture copy operations.
+    ///
+    /// Returns an error if the renderer doesn't support this blend mode.
+    #[inline]
+    pub fn set_custom_blend_mode(&mut self, blend: CustomBlendMode) -> Result<(), String> {
+        InternalTexture{ raw: self.raw }.set_custom_blend_mode(blend)
+    }
+
     /// Gets the blend mode used for texture copy operations.
+    ///
+    /// Returns `BlendMode::Invalid` if a custom blend mode is set.
     #[inline]
     pub fn blend_mode(&self) -> BlendMode {
         InternalTexture{ raw: self.raw }.blend_mode()
@@ -2189,6 +2877,10 @@ impl<> Texture<> {
     }
 
     /// Updates a rectangle within a planar YV12 or IYUV texture with new pixel data.
+    ///
+    /// The `y_plane` must hold `y_pitch` bytes for each row of `rect`, and the `u_plane` and
+    /// `v_plane` their pitch for each row of half of it. Returns an error for other texture
+    /// formats, or if the lengths of the planes don't match.
     #[inline]
     pub fn update_yuv<R>(&mut self,
                          rect: R,
@@ -2221,6 +2913,31 @@ impl<> Texture<> {
         InternalTexture { raw: self.raw }.with_lock(rect, func)
     }
 
+    /// Locks the texture for **write-only** pixel access, as typed pixels.
+    /// The texture must have been created with streaming access.
+    ///
+    /// `F` is a function that is passed the write-only texture buffer as a slice of `T`, and
+    /// the number of `T` per row of the slice, which may be larger than the width of the
+    /// locked area. For instance, use `u32` to write pixels of a `PixelFormatEnum::ARGB8888`
+    /// texture, or `[u8; 3]` for `PixelFormatEnum::RGB24`.
+    ///
+    /// The slice usually is the texture buffer itself, with `pitch / size_of::<T>()` elements
+    /// per row. When SDL pads rows to a size that isn't a multiple of the size of `T`, or the
+    /// buffer isn't aligned for `T`, `F` is given a buffer of packed rows instead, which is
+    /// copied to the texture once `F` returns.
+    ///
+    /// Returns an error if the texture wasn't created with streaming access, if the size of
+    /// `T` isn't the size of a pixel of the texture format, if the format is planar or indexed
+    /// under 8 bits, or if the texture can't be locked.
+    #[inline]
+    pub fn with_lock_as<T, F, R, R2>(&mut self, rect: R2, func: F) -> Result<R, String>
+        where T: TexturePixel,
+              F: FnOnce(&mut [T], usize) -> R,
+              R2: Into<Option<Rect>>
+    {
+        InternalTexture { raw: self.raw }.with_lock_as(rect, func)
+    }
+
     /// Binds an OpenGL/ES/ES2 texture to the current
     /// context for use with when rendering OpenGL primitives directly.
     #[inline]
@@ -2292,3 +3009,50 @@ pub fn drivers() -> DriverIterator {
         index: 0,
     }
 }
+
+/// Gets the index of the render driver with the given name, such as
+/// `"software"`, `"opengl"` or `"direct3d"`, to be passed to
+/// `CanvasBuilder::index`.
+///
+/// Returns `None` if no such driver is compiled into the SDL2 library.
+pub fn driver_index(name: &str) -> Option<u32> {
+    drivers().position(|info| info.name == name).map(|index| index as u32)
+}
+
+// `ll::SDL_Vertex` isn't generated by bindgen, so check it matches the C layout.
+#[test]
+fn vertex_layout() {
+    use std::mem;
+
+    let vertex = Vertex::new((0.0, 0.0), pixels::Color::WHITE, (0.0, 0.0));
+    let base = &vertex.raw as *const _ as usize;
+
+    assert_eq!(mem::size_of::<ll::SDL_Vertex>(), 20);
+    assert_eq!(mem::align_of::<ll::SDL_Vertex>(), 4);
+    assert_eq!(&vertex.raw.color as *const _ as usize - base, 8);
+    assert_eq!(&vertex.raw.tex_coord as *const _ as usize - base, 12);
+}
+
+// Lines drawn in several batches must stay connected: each batch starts where the previous one
+// ended, and together the batches cover every point exactly once apart from those joins.
+#[test]
+fn line_batches_stay_connected() {
+    for &count in &[0, 1, 2, DRAW_BATCH_SIZE - 1, DRAW_BATCH_SIZE, DRAW_BATCH_SIZE + 1,
+                    2 * DRAW_BATCH_SIZE - 1, 3 * DRAW_BATCH_SIZE + 7] {
+        let points: Vec<Point> = (0..count as i32).map(|i| Point::new(i, -i)).collect();
+        let mut batches: Vec<Vec<Point>> = Vec::new();
+        for_each_line_batch(points.iter().cloned(), |batch| {
+            batches.push(batch.to_vec());
+            Ok(())
+        }).unwrap();
+
+        for pair in batches.windows(2) {
+            assert_eq!(pair[0].last(), pair[1].first());
+        }
+        let mut joined: Vec<Point> = batches.first().cloned().unwrap_or_default();
+        for batch in batches.iter().skip(1) {
+            joined.extend_from_slice(&batch[1..]);
+        }
+        assert_eq!(joined, points, "{} points", count);
+    }
+}
@@ -1,11 +1,16 @@
+use std::any::Any;
+use std::cell::RefCell;
 use std::ffi::CString;
 use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
 use std::path::Path;
 use std::marker::PhantomData;
 use libc::{c_int, size_t, c_char};
 use libc::c_void;
-use crate::get_error;
+use crate::{get_error, set_error_from_code, Error};
 use std::mem::transmute;
+use std::convert::TryFrom;
 
 use crate::sys;
 
@@ -26,10 +31,21 @@ impl<'a> RWops<'a> {
     }
 
     /// Creates an SDL file stream.
+    ///
+    /// Returns an error if the path isn't valid UTF-8 or contains a NUL byte, or if SDL can't
+    /// open the file.
     pub fn from_file<P: AsRef<Path>>(path: P, mode: &str) -> Result<RWops <'static>, String> {
+        let path = path.as_ref();
+        let path_c = match path.to_str().map(CString::new) {
+            Some(Ok(s)) => s,
+            Some(Err(_)) => return Err(format!("Invalid path {:?}: contains a NUL byte", path)),
+            None => return Err(format!("Invalid path {:?}: not valid UTF-8", path)),
+        };
+        let mode_c = match CString::new(mode) {
+            Ok(s) => s,
+            Err(_) => return Err(format!("Invalid mode {:?}: contains a NUL byte", mode)),
+        };
         let raw = unsafe {
-            let path_c = CString::new(path.as_ref().to_str().unwrap()).unwrap();
-            let mode_c = CString::new(mode).unwrap();
             sys::SDL_RWFromFile(path_c.as_ptr() as *const c_char, mode_c.as_ptr() as *const c_char)
         };
 
@@ -76,6 +92,41 @@ impl<'a> RWops<'a> {
         }
     }
 
+    /// Wraps any `Read + Seek` object, such as a decompressing reader, in a read-only `RWops`.
+    ///
+    /// SDL reads from and seeks `source` through callbacks, and `source` is dropped when the
+    /// `RWops` is closed. Writing to the returned stream always fails.
+    ///
+    /// The stream's size is found by seeking to the end of `source` and back. If either seek
+    /// fails, the length is unknown: SDL's `size` callback returns -1, so `SDL_RWsize` reports
+    /// -1 and `len()` returns `None`.
+    ///
+    /// A panic in `source` can't unwind through SDL, so it is caught and the callback reports
+    /// an error instead. The panic is resumed by the next `RWops` method called on the same
+    /// thread: `len`, `read`, `write`, `seek`, or dropping an `RWops`.
+    pub fn from_read_seek<T>(source: T) -> Result<RWops<'a>, String>
+        where T: io::Read + io::Seek + 'a {
+        let raw = unsafe { sys::SDL_AllocRW() };
+        if raw.is_null() {
+            return Err(get_error());
+        }
+
+        unsafe {
+            (*raw).size = Some(read_seek_size::<T>);
+            (*raw).seek = Some(read_seek_seek::<T>);
+            (*raw).read = Some(read_seek_read::<T>);
+            (*raw).write = Some(read_seek_write);
+            (*raw).close = Some(read_seek_close::<T>);
+            (*raw).type_ = sys::SDL_RWOPS_UNKNOWN;
+            (*raw).hidden.unknown.data1 = Box::into_raw(Box::new(source)) as *mut c_void;
+        }
+
+        Ok(RWops {
+            raw,
+            _marker: PhantomData
+        })
+    }
+
     /// Prepares a read-write memory buffer for use with `RWops`.
     ///
     /// This method can only fail if the buffer size is zero.
@@ -100,6 +151,7 @@ impl<'a> RWops<'a> {
     /// (either because it doesn't make sense for the stream type, or there was an error).
     pub fn len(&self) -> Option<usize> {
         let result = unsafe { ((*self.raw).size.unwrap())(self.raw) };
+        resume_callback_panic();
 
         match result {
             -1 => None,
@@ -116,9 +168,126 @@ impl<'a> RWops<'a> {
     }
 }
 
+thread_local! {
+    /// The payload of a panic caught in a `from_read_seek` callback, until it is resumed.
+    static CALLBACK_PANIC: RefCell<Option<Box<dyn Any + Send>>> = RefCell::new(None);
+}
+
+/// Runs the body of a `from_read_seek` callback, returning `error` if it panics.
+fn catch_callback_panic<R, F: FnOnce() -> R>(error: R, f: F) -> R {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            CALLBACK_PANIC.with(|p| *p.borrow_mut() = Some(payload));
+            error
+        }
+    }
+}
+
+/// Resumes a panic caught by `catch_callback_panic` on this thread, if any.
+fn resume_callback_panic() {
+    if let Some(payload) = CALLBACK_PANIC.with(|p| p.borrow_mut().take()) {
+        panic::resume_unwind(payload);
+    }
+}
+
+unsafe fn read_seek_source<'s, T>(context: *mut sys::SDL_RWops) -> &'s mut T {
+    &mut *((*context).hidden.unknown.data1 as *mut T)
+}
+
+unsafe extern "C" fn read_seek_size<T: io::Read + io::Seek>(context: *mut sys::SDL_RWops) -> i64 {
+    let source = read_seek_source::<T>(context);
+    catch_callback_panic(-1, || {
+        let size = source.seek(io::SeekFrom::Current(0)).and_then(|current| {
+            let end = source.seek(io::SeekFrom::End(0))?;
+            source.seek(io::SeekFrom::Start(current))?;
+            Ok(end)
+        });
+        match size {
+            Ok(size) => size as i64,
+            Err(_) => -1,
+        }
+    })
+}
+
+unsafe extern "C" fn read_seek_seek<T: io::Read + io::Seek>(context: *mut sys::SDL_RWops,
+                                                           offset: i64,
+                                                           whence: c_int) -> i64 {
+    let pos = match whence as u32 {
+        sys::RW_SEEK_SET if offset >= 0 => io::SeekFrom::Start(offset as u64),
+        sys::RW_SEEK_CUR => io::SeekFrom::Current(offset),
+        sys::RW_SEEK_END => io::SeekFrom::End(offset),
+        _ => {
+            set_error_from_code(Error::SeekError);
+            return -1;
+        }
+    };
+    let source = read_seek_source::<T>(context);
+    catch_callback_panic(-1, || match source.seek(pos) {
+        Ok(pos) => pos as i64,
+        Err(_) => {
+            set_error_from_code(Error::SeekError);
+            -1
+        }
+    })
+}
+
+unsafe extern "C" fn read_seek_read<T: io::Read + io::Seek>(context: *mut sys::SDL_RWops,
+                                                           ptr: *mut c_void,
+                                                           size: sys::size_t,
+                                                           maxnum: sys::size_t) -> sys::size_t {
+    if size == 0 || maxnum == 0 {
+        return 0;
+    }
+    let len = match size.checked_mul(maxnum).and_then(|len| usize::try_from(len).ok()) {
+        Some(len) => len,
+        None => {
+            set_error_from_code(Error::ReadError);
+            return 0;
+        }
+    };
+    let source = read_seek_source::<T>(context);
+    let buf = ::std::slice::from_raw_parts_mut(ptr as *mut u8, len);
+    catch_callback_panic(0, || {
+        let mut read = 0;
+        while read < buf.len() {
+            match source.read(&mut buf[read..]) {
+                Ok(0) => break,
+                Ok(n) => read += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(_) => {
+                    set_error_from_code(Error::ReadError);
+                    break;
+                }
+            }
+        }
+        read as sys::size_t / size
+    })
+}
+
+unsafe extern "C" fn read_seek_write(_context: *mut sys::SDL_RWops,
+                                     _ptr: *const c_void,
+                                     _size: sys::size_t,
+                                     _num: sys::size_t) -> sys::size_t {
+    set_error_from_code(Error::WriteError);
+    0
+}
+
+unsafe extern "C" fn read_seek_close<T: io::Read + io::Seek>(context: *mut sys::SDL_RWops) -> c_int {
+    if !context.is_null() {
+        let source = Box::from_raw((*context).hidden.unknown.data1 as *mut T);
+        catch_callback_panic((), || drop(source));
+        sys::SDL_FreeRW(context);
+    }
+    0
+}
+
 impl<'a> Drop for RWops<'a> {
     fn drop(&mut self) {
         let ret = unsafe { ((*self.raw).close.unwrap())(self.raw) };
+        if !thread::panicking() {
+            resume_callback_panic();
+        }
         if ret != 0 {
             panic!(get_error());
         }
@@ -133,6 +302,7 @@ impl<'a> io::Read for RWops<'a> {
         let ret = unsafe {
             ((*self.raw).read.unwrap())(self.raw, buf.as_ptr() as *mut c_void, 1, out_len as u64)
         };
+        resume_callback_panic();
         Ok(ret as usize)
     }
 }
@@ -143,6 +313,7 @@ impl<'a> io::Write for RWops<'a> {
         let ret = unsafe {
             ((*self.raw).write.unwrap())(self.raw, buf.as_ptr() as *const c_void, 1, in_len as u64)
         };
+        resume_callback_panic();
         Ok(ret as usize)
     }
 
@@ -162,6 +333,7 @@ impl<'a> io::Seek for RWops<'a> {
         let ret = unsafe {
             ((*self.raw).seek.unwrap())(self.raw, offset, transmute(whence))
         };
+        resume_callback_panic();
         if ret == -1 {
             Err(io::Error::last_os_error())
         } else {
@@ -169,3 +341,82 @@ impl<'a> io::Seek for RWops<'a> {
         }
     }
 }
+
+
+#[cfg(test)]
+mod test {
+    use std::io::{self, Cursor, Read, Seek, SeekFrom};
+    use std::panic;
+    use super::RWops;
+
+    #[test]
+    fn test_from_read_seek() {
+        let data: Vec<u8> = (0..10).collect();
+        let mut rwops = RWops::from_read_seek(Cursor::new(data)).unwrap();
+        assert_eq!(rwops.len(), Some(10));
+
+        assert_eq!(rwops.seek(SeekFrom::Start(4)).unwrap(), 4);
+        // Finding the size must not move the stream.
+        assert_eq!(rwops.len(), Some(10));
+
+        let mut buf = [0; 3];
+        assert_eq!(rwops.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf, [4, 5, 6]);
+        assert_eq!(rwops.seek(SeekFrom::Current(0)).unwrap(), 7);
+
+        assert_eq!(rwops.seek(SeekFrom::End(-1)).unwrap(), 9);
+        assert_eq!(rwops.read(&mut buf).unwrap(), 1);
+        assert_eq!(buf[0], 9);
+        assert_eq!(rwops.read(&mut buf).unwrap(), 0);
+    }
+
+    struct Unseekable;
+
+    impl Read for Unseekable {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Ok(0)
+        }
+    }
+
+    impl Seek for Unseekable {
+        fn seek(&mut self, _pos: SeekFrom) -> io::Result<u64> {
+            Err(io::Error::new(io::ErrorKind::Other, "not seekable"))
+        }
+    }
+
+    #[test]
+    fn test_from_read_seek_unknown_size() {
+        let mut rwops = RWops::from_read_seek(Unseekable).unwrap();
+        assert_eq!(rwops.len(), None);
+        assert!(rwops.is_empty());
+        assert!(rwops.seek(SeekFrom::Start(0)).is_err());
+    }
+
+    struct PanickingReader;
+
+    impl Read for PanickingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            panic!("read failed");
+        }
+    }
+
+    impl Seek for PanickingReader {
+        fn seek(&mut self, _pos: SeekFrom) -> io::Result<u64> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn test_from_read_seek_panic_is_resumed() {
+        let mut rwops = RWops::from_read_seek(PanickingReader).unwrap();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let mut buf = [0; 4];
+            let _ = rwops.read(&mut buf);
+        }));
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"read failed"));
+
+        // The panic has been resumed, so the stream keeps working.
+        assert_eq!(rwops.len(), Some(0));
+    }
+}
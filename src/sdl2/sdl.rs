@@ -110,6 +110,10 @@ impl Sdl {
     #[inline]
     pub fn game_controller(&self) -> Result<GameControllerSubsystem, String> { GameControllerSubsystem::new(self) }
 
+    /// Initializes the sensor subsystem.
+    #[inline]
+    pub fn sensor(&self) -> Result<SensorSubsystem, String> { SensorSubsystem::new(self) }
+
     /// Initializes the timer subsystem.
     #[inline]
     pub fn timer(&self) -> Result<TimerSubsystem, String> { TimerSubsystem::new(self) }
@@ -243,6 +247,7 @@ subsystem!(AudioSubsystem, sys::SDL_INIT_AUDIO, nosync);
 subsystem!(GameControllerSubsystem, sys::SDL_INIT_GAMECONTROLLER, nosync);
 subsystem!(HapticSubsystem, sys::SDL_INIT_HAPTIC, nosync);
 subsystem!(JoystickSubsystem, sys::SDL_INIT_JOYSTICK, nosync);
+subsystem!(SensorSubsystem, sys::SDL_INIT_SENSOR, nosync);
 subsystem!(VideoSubsystem, sys::SDL_INIT_VIDEO, nosync);
 // Timers can be added on other threads.
 subsystem!(TimerSubsystem, sys::SDL_INIT_TIMER, sync);
@@ -296,7 +301,10 @@ impl Drop for EventPump {
     }
 }
 
-/// Get platform name
+/// Get the name of the platform SDL is running on, such as `"Windows"`,
+/// `"Mac OS X"`, `"Linux"`, `"iOS"` or `"Android"`.
+///
+/// The string is static; call `to_owned()` on it if you need a `String`.
 #[inline]
 pub fn get_platform() -> &'static str {
   unsafe {
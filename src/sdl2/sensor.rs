@@ -0,0 +1,201 @@
+//! Sensor Functions
+//!
+//! Sensors such as the accelerometer and gyroscope of a phone or a game controller.
+//! Requires SDL 2.0.9.
+use crate::sys;
+
+use crate::SensorSubsystem;
+use crate::get_error;
+use std::ffi::CStr;
+use libc::c_int;
+use crate::common::{validate_int, IntegerOrSdlError};
+
+mod ll {
+    use libc::c_int;
+    use crate::sys;
+
+    // `SDL_SensorType` is returned as a plain integer since newer SDL versions add values to it.
+    extern "C" {
+        pub fn SDL_SensorGetDeviceType(device_index: c_int) -> c_int;
+        pub fn SDL_SensorGetType(sensor: *mut sys::SDL_Sensor) -> c_int;
+    }
+}
+
+impl SensorSubsystem {
+    /// Retrieve the total number of sensors identified by SDL.
+    pub fn num_sensors(&self) -> Result<u32, String> {
+        let result = unsafe { sys::SDL_NumSensors() };
+
+        if result >= 0 {
+            Ok(result as u32)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Attempt to open the sensor at index `sensor_index` and return it.
+    ///
+    /// While it is open, the sensor sends `Event::SensorUpdate` events.
+    pub fn open(&self, sensor_index: u32) -> Result<Sensor, IntegerOrSdlError> {
+        use crate::common::IntegerOrSdlError::*;
+        let sensor_index = validate_int(sensor_index, "sensor_index")?;
+
+        let sensor = unsafe { sys::SDL_SensorOpen(sensor_index) };
+
+        if sensor.is_null() {
+            Err(SdlError(get_error()))
+        } else {
+            Ok(Sensor {
+                subsystem: self.clone(),
+                raw: sensor
+            })
+        }
+    }
+
+    /// Return the name of the sensor at index `sensor_index`.
+    pub fn name_for_index(&self, sensor_index: u32) -> Result<String, IntegerOrSdlError> {
+        use crate::common::IntegerOrSdlError::*;
+        let sensor_index = validate_int(sensor_index, "sensor_index")?;
+
+        let c_str = unsafe { sys::SDL_SensorGetDeviceName(sensor_index) };
+
+        if c_str.is_null() {
+            Err(SdlError(get_error()))
+        } else {
+            Ok(unsafe {
+                CStr::from_ptr(c_str as *const _).to_string_lossy().into_owned()
+            })
+        }
+    }
+
+    /// Return the type of the sensor at index `sensor_index`.
+    pub fn sensor_type_for_index(&self, sensor_index: u32) -> Result<SensorType, IntegerOrSdlError> {
+        use crate::common::IntegerOrSdlError::*;
+        let sensor_index = validate_int(sensor_index, "sensor_index")?;
+
+        let result = unsafe { ll::SDL_SensorGetDeviceType(sensor_index) };
+
+        match SensorType::from_ll(result) {
+            Some(sensor_type) => Ok(sensor_type),
+            None => Err(SdlError(get_error())),
+        }
+    }
+
+    /// Return the instance id of the sensor at index `sensor_index`.
+    pub fn instance_id_for_index(&self, sensor_index: u32) -> Result<u32, IntegerOrSdlError> {
+        use crate::common::IntegerOrSdlError::*;
+        let sensor_index = validate_int(sensor_index, "sensor_index")?;
+
+        let result = unsafe { sys::SDL_SensorGetDeviceInstanceID(sensor_index) };
+
+        if result < 0 {
+            Err(SdlError(get_error()))
+        } else {
+            Ok(result as u32)
+        }
+    }
+
+    /// Force sensor update when not using the event loop
+    #[inline]
+    pub fn update(&self) {
+        unsafe { sys::SDL_SensorUpdate() };
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SensorType {
+    Unknown,
+    /// Reports the acceleration in m/s², including gravity, on the X, Y and Z axes.
+    Accelerometer,
+    /// Reports the angular speed in radians per second around the X, Y and Z axes.
+    Gyroscope,
+}
+
+impl SensorType {
+    /// Converts an `SDL_SensorType` value. Returns `None` for `SDL_SENSOR_INVALID`, which SDL
+    /// returns on error; types this crate doesn't know about become `Unknown`.
+    pub fn from_ll(raw: c_int) -> Option<SensorType> {
+        match raw {
+            -1 => None,
+            1 => Some(SensorType::Accelerometer),
+            2 => Some(SensorType::Gyroscope),
+            _ => Some(SensorType::Unknown),
+        }
+    }
+
+    pub fn to_ll(self) -> c_int {
+        match self {
+            SensorType::Unknown => sys::SDL_SensorType::SDL_SENSOR_UNKNOWN as c_int,
+            SensorType::Accelerometer => sys::SDL_SensorType::SDL_SENSOR_ACCEL as c_int,
+            SensorType::Gyroscope => sys::SDL_SensorType::SDL_SENSOR_GYRO as c_int,
+        }
+    }
+}
+
+/// Wrapper around the `SDL_Sensor` object
+pub struct Sensor {
+    subsystem: SensorSubsystem,
+    raw: *mut sys::SDL_Sensor
+}
+
+impl Sensor {
+    #[inline]
+    pub const fn subsystem(&self) -> &SensorSubsystem { &self.subsystem }
+
+    /// Return the name of the sensor or an empty string if no name
+    /// is found.
+    pub fn name(&self) -> String {
+        let name = unsafe { sys::SDL_SensorGetName(self.raw) };
+
+        if name.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(name as *const _).to_string_lossy().into_owned() }
+        }
+    }
+
+    pub fn sensor_type(&self) -> SensorType {
+        let result = unsafe { ll::SDL_SensorGetType(self.raw) };
+
+        match SensorType::from_ll(result) {
+            Some(sensor_type) => sensor_type,
+            // Should only fail if the sensor is NULL.
+            None => panic!("{}", get_error()),
+        }
+    }
+
+    pub fn instance_id(&self) -> u32 {
+        let result = unsafe { sys::SDL_SensorGetInstanceID(self.raw) };
+
+        if result < 0 {
+            // Should only fail if the sensor is NULL.
+            panic!("{}", get_error())
+        } else {
+            result as u32
+        }
+    }
+
+    /// Fill `data` with the current values of the sensor.
+    ///
+    /// Accelerometers and gyroscopes report three values, for the X, Y and Z axes.
+    pub fn get_data(&self, data: &mut [f32]) -> Result<(), String> {
+        let len = if data.len() > c_int::max_value() as usize {
+            c_int::max_value()
+        } else {
+            data.len() as c_int
+        };
+        let result = unsafe { sys::SDL_SensorGetData(self.raw, data.as_mut_ptr(), len) };
+
+        if result != 0 {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for Sensor {
+    fn drop(&mut self) {
+        unsafe { sys::SDL_SensorClose(self.raw) }
+    }
+}
@@ -185,6 +185,7 @@ impl<'a> Surface<'a> {
         }
     }
 
+    /// Loads a BMP image from a file.
     pub fn load_bmp<P: AsRef<Path>>(path: P) -> Result<Surface<'static>, String> {
         let mut file = RWops::from_file(path, "rb")?;
         Surface::load_bmp_rw(&mut file)
@@ -331,11 +332,19 @@ impl SurfaceRef {
         else { Err(get_error()) }
     }
 
+    /// Saves the surface to a file as a BMP image.
     pub fn save_bmp<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
         let mut file = RWops::from_file(path, "wb")?;
         self.save_bmp_rw(&mut file)
     }
 
+    /// Sets the palette used by an indexed (8 bits per pixel or fewer) surface.
+    ///
+    /// The surface keeps its own reference to the palette, so `palette` may be
+    /// dropped afterwards. Later changes made through `Palette::set_colors`
+    /// still affect this surface.
+    ///
+    /// Errors if the surface format has no palette.
     pub fn set_palette(&mut self, palette: &pixels::Palette) -> Result<(), String> {
         let result = unsafe { sys::SDL_SetSurfacePalette(self.raw(), palette.raw()) };
 
@@ -345,6 +354,9 @@ impl SurfaceRef {
         }
     }
 
+    /// Enables RLE acceleration, which speeds up blitting surfaces with a color key or
+    /// large transparent areas. The surface must be locked before accessing its pixels
+    /// directly while RLE is enabled.
     #[allow(non_snake_case)]
     pub fn enable_RLE(&mut self) {
         let result = unsafe { sys::SDL_SetSurfaceRLE(self.raw(), 1) };
@@ -355,6 +367,7 @@ impl SurfaceRef {
         }
     }
 
+    /// Disables RLE acceleration.
     #[allow(non_snake_case)]
     pub fn disable_RLE(&mut self) {
         let result = unsafe { sys::SDL_SetSurfaceRLE(self.raw(), 0) };
@@ -365,6 +378,12 @@ impl SurfaceRef {
         }
     }
 
+    /// Sets the color that is treated as transparent when blitting the surface, or disables
+    /// the color key if `enable` is `false`.
+    ///
+    /// The color is converted to the pixel format of the surface, so it must be exactly
+    /// representable in that format to match the pixels of the surface, e.g. magenta is
+    /// `Color::RGB(255, 0, 255)`. For indexed surfaces, the closest palette entry is used.
     pub fn set_color_key(&mut self, enable: bool, color: pixels::Color) -> Result<(), String> {
         let key = color.to_u32(&self.pixel_format());
         let result = unsafe {
@@ -377,6 +396,8 @@ impl SurfaceRef {
         }
     }
 
+    /// Returns the color key of the surface, converted back from its pixel format.
+    ///
     /// The function will fail if the surface doesn't have color key enabled.
     pub fn color_key(&self) -> Result<pixels::Color, String> {
         let mut key = 0;
@@ -439,17 +460,23 @@ impl SurfaceRef {
         }
     }
 
-    #[allow(clippy::clone_on_copy)]
+    /// Fills several rectangles with the same color in a single call, as if calling
+    /// `fill_rect` for each of them.
     pub fn fill_rects(&mut self, rects: &[Rect], color: pixels::Color) -> Result<(), String>
     {
-        for rect in rects.iter() {
-            if let Err(e) = self.fill_rect(rect.clone(), color)
-            {
-                return Err(e);
-            }
+        if rects.is_empty() {
+            return Ok(());
         }
 
-        Ok(())
+        let format = self.pixel_format();
+        let result = unsafe {
+            sys::SDL_FillRects(self.raw(), Rect::raw_slice(rects), rects.len() as c_int,
+                               color.to_u32(&format))
+        };
+        match result {
+            0 => Ok(()),
+            _ => Err(get_error())
+        }
     }
 
     pub fn set_alpha_mod(&mut self, alpha: u8) {
@@ -534,6 +561,8 @@ impl SurfaceRef {
     }
 
     /// Copies the surface into a new one that is optimized for blitting to a surface of a specified pixel format.
+    ///
+    /// The returned surface owns its pixels, independently of this one.
     pub fn convert(&self, format: &pixels::PixelFormat) -> Result<Surface<'static>, String> {
         // SDL_ConvertSurface takes a flag as the last parameter, which should be 0 by the docs.
         let surface_ptr = unsafe { sys::SDL_ConvertSurface(self.raw(), format.raw(), 0u32) };
@@ -545,7 +574,10 @@ impl SurfaceRef {
         }
     }
 
-    /// Copies the surface into a new one of a specified pixel format.
+    /// Copies the surface into a new one of a specified pixel format, e.g.
+    /// `PixelFormatEnum::ARGB8888`.
+    ///
+    /// The returned surface owns its pixels, independently of this one.
     pub fn convert_format(&self, format: pixels::PixelFormatEnum) -> Result<Surface<'static>, String> {
         // SDL_ConvertSurfaceFormat takes a flag as the last parameter, which should be 0 by the docs.
         let surface_ptr = unsafe { sys::SDL_ConvertSurfaceFormat(self.raw(), format as u32, 0u32) };
@@ -616,21 +648,22 @@ impl SurfaceRef {
 
     /// Performs scaled surface bliting (surface copying).
     ///
-    /// Returns the final blit rectangle, if a `dst_rect` was provided.
+    /// If `src_rect` is `None`, the whole surface is copied. If `dst_rect` is `None`,
+    /// the copy is scaled to the whole `dst` surface.
+    ///
+    /// Returns the final blit rectangle, after clipping, if a `dst_rect` was provided.
     pub fn blit_scaled<R1, R2>(&self, src_rect: R1,
                              dst: &mut SurfaceRef, dst_rect: R2) -> Result<Option<Rect>, String>
     where R1: Into<Option<Rect>>,
           R2: Into<Option<Rect>>,
     {
         let src_rect = src_rect.into();
-        let dst_rect = dst_rect.into();
+        // Copy the rect here to make a mutable copy without requiring
+        // a mutable argument
+        let mut dst_rect = dst_rect.into();
 
         match unsafe {
             let src_rect_ptr = src_rect.as_ref().map(|r| r.raw()).unwrap_or(ptr::null());
-
-            // Copy the rect here to make a mutable copy without requiring
-            // a mutable argument
-            let mut dst_rect = dst_rect;
             let dst_rect_ptr = dst_rect.as_mut().map(|r| r.raw_mut())
                 .unwrap_or(ptr::null_mut());
             sys::SDL_UpperBlitScaled(self.raw(), src_rect_ptr, dst.raw(), dst_rect_ptr)
@@ -1,6 +1,10 @@
 use libc::c_void;
+use std::collections::HashMap;
 use std::marker::PhantomData;
-use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
 use crate::sys;
 
 use crate::TimerSubsystem;
@@ -11,20 +15,29 @@ impl TimerSubsystem {
     /// The timer is started immediately, it will be cancelled either:
     ///
     /// * when the timer is dropped
-    /// * or when the callback returns a non-positive continuation interval
+    /// * or when the callback returns `0`
+    ///
+    /// Otherwise the value returned by the callback is the number of milliseconds until it is
+    /// called again. The callback runs on a separate thread, which is why it must be `Send`. It
+    /// must also be `'static`, as the timer keeps running if the `Timer` is leaked, e.g. with
+    /// `mem::forget`. A panic in the callback is caught and cancels the timer.
+    ///
+    /// Dropping the timer waits for a callback currently running on the timer thread to
+    /// return, so the closure is never dropped while SDL may still call it.
     #[must_use = "if unused the Timer will be dropped immediately"]
-    pub fn add_timer<'b, 'c>(&'b self, delay: u32, callback: TimerCallback<'c>) -> Timer<'b, 'c> {
-        unsafe {
-            let callback = Box::new(callback);
-            let timer_id = sys::SDL_AddTimer(delay,
-                                            Some(c_timer_callback),
-                                            mem::transmute_copy(&callback));
-
-            Timer {
-                callback: Some(callback),
-                raw: timer_id,
-                _marker: PhantomData
-            }
+    pub fn add_timer<'b>(&'b self, delay: u32, callback: TimerCallback) -> Timer<'b> {
+        let callback = Arc::new(Mutex::new(Some(callback)));
+        let key = NEXT_TIMER_KEY.fetch_add(1, Ordering::Relaxed);
+        lock_timers().insert(key, callback.clone());
+        let timer_id = unsafe {
+            sys::SDL_AddTimer(delay, Some(c_timer_callback), key as *mut c_void)
+        };
+
+        Timer {
+            callback,
+            key,
+            raw: timer_id,
+            _marker: PhantomData
         }
     }
 
@@ -53,37 +66,123 @@ impl TimerSubsystem {
     }
 }
 
-pub type TimerCallback<'a> = Box<dyn FnMut() -> u32+'a+Sync>;
+/// A point in time measured with the high resolution performance counter.
+///
+/// This is monotonic and usually has sub-millisecond precision, which makes it suitable for
+/// measuring frame times.
+///
+/// ```no_run
+/// use sdl2::timer::PerformanceInstant;
+///
+/// let frame_start = PerformanceInstant::now();
+/// // ... render the frame ...
+/// println!("frame took {:?}", frame_start.elapsed());
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct PerformanceInstant {
+    counter: u64
+}
+
+impl PerformanceInstant {
+    /// Returns the current value of the performance counter.
+    pub fn now() -> PerformanceInstant {
+        PerformanceInstant {
+            counter: unsafe { sys::SDL_GetPerformanceCounter() }
+        }
+    }
+
+    /// Returns the time elapsed since this instant was created.
+    pub fn elapsed(&self) -> Duration {
+        PerformanceInstant::now().duration_since(*self)
+    }
+
+    /// Returns the time elapsed from `earlier` to `self`, or a zero duration if `earlier` is
+    /// later than `self`.
+    pub fn duration_since(&self, earlier: PerformanceInstant) -> Duration {
+        let frequency = unsafe { sys::SDL_GetPerformanceFrequency() };
+        counter_delta_to_duration(self.counter.saturating_sub(earlier.counter), frequency)
+    }
+}
+
+fn counter_delta_to_duration(delta: u64, frequency: u64) -> Duration {
+    if frequency == 0 {
+        return Duration::from_secs(0);
+    }
+    let secs = delta / frequency;
+    let nanos = (u128::from(delta % frequency) * 1_000_000_000 / u128::from(frequency)) as u32;
+    Duration::new(secs, nanos)
+}
+
+pub type TimerCallback = Box<dyn FnMut() -> u32 + Send>;
+
+type TimerSlot = Mutex<Option<TimerCallback>>;
+
+lazy_static! {
+    /// The callbacks SDL may still call, by the key it passes to `c_timer_callback`.
+    ///
+    /// SDL is only given the key, so a `Timer` can release its callback when dropped even
+    /// though SDL may be about to call the trampoline: the key is simply not found then.
+    static ref TIMERS: Mutex<HashMap<usize, Arc<TimerSlot>>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_TIMER_KEY: AtomicUsize = AtomicUsize::new(0);
+
+fn lock_timers() -> MutexGuard<'static, HashMap<usize, Arc<TimerSlot>>> {
+    TIMERS.lock().unwrap_or_else(|e| e.into_inner())
+}
 
-pub struct Timer<'b, 'a> {
-    callback: Option<Box<TimerCallback<'a>>>,
+fn lock_slot(slot: &TimerSlot) -> MutexGuard<'_, Option<TimerCallback>> {
+    // A panicking callback can't leave the slot in an inconsistent state.
+    slot.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub struct Timer<'b> {
+    callback: Arc<TimerSlot>,
+    key: usize,
     raw: sys::SDL_TimerID,
     _marker: PhantomData<&'b ()>
 }
 
-impl<'b, 'a> Timer<'b, 'a> {
+impl<'b> Timer<'b> {
     /// Returns the closure as a trait-object and cancels the timer
     /// by consuming it...
-    pub fn into_inner(mut self) -> TimerCallback<'a> {
-        *self.callback.take().unwrap()
+    pub fn into_inner(self) -> TimerCallback {
+        lock_slot(&self.callback).take().unwrap()
     }
 }
 
-impl<'b, 'a> Drop for Timer<'b, 'a> {
+impl<'b> Drop for Timer<'b> {
     #[inline]
     fn drop(&mut self) {
         // SDL_RemoveTimer returns SDL_FALSE if the timer wasn't found (impossible),
         // or the timer has been cancelled via the callback (possible).
         // The timer being cancelled isn't an issue, so we ignore the result.
         unsafe { sys::SDL_RemoveTimer(self.raw) };
+        // SDL_RemoveTimer doesn't wait for a running callback, and SDL may still call the
+        // trampoline once; it won't find the key anymore and cancels.
+        lock_timers().remove(&self.key);
+        // Taking the closure out under the lock waits for a call that is already running.
+        lock_slot(&self.callback).take();
     }
 }
 
 extern "C" fn c_timer_callback(_interval: u32, param: *mut c_void) -> u32 {
-    let f = param as *mut std::boxed::Box<dyn std::ops::Fn() -> u32>;
-    unsafe {
-        (*f)()
+    let key = param as usize;
+    let slot = match lock_timers().get(&key) {
+        Some(slot) => slot.clone(),
+        None => return 0,
+    };
+    let next = match *lock_slot(&slot) {
+        // Unwinding into SDL's timer thread is undefined behaviour, so a panic cancels the
+        // timer instead.
+        Some(ref mut callback) => panic::catch_unwind(AssertUnwindSafe(|| callback())).unwrap_or(0),
+        None => 0,
+    };
+    if next == 0 {
+        // SDL won't call us again.
+        lock_timers().remove(&key);
     }
+    next
 }
 
 
@@ -93,11 +192,23 @@ mod test {
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
+    #[test]
+    fn test_counter_delta_to_duration() {
+        use super::counter_delta_to_duration;
+
+        assert_eq!(counter_delta_to_duration(0, 1000), Duration::from_secs(0));
+        assert_eq!(counter_delta_to_duration(2500, 1000), Duration::from_millis(2500));
+        assert_eq!(counter_delta_to_duration(1, 1_000_000_000), Duration::from_nanos(1));
+        assert_eq!(counter_delta_to_duration(10, 0), Duration::from_secs(0));
+    }
+
     #[test]
     fn test_timer() {
         test_timer_runs_multiple_times();
         test_timer_runs_at_least_once();
         test_timer_can_be_recreated();
+        test_dropped_timer_is_released();
+        test_panicking_timer_is_cancelled();
     }
 
     fn test_timer_runs_multiple_times() {
@@ -107,7 +218,7 @@ mod test {
         let local_num = Arc::new(Mutex::new(0));
         let timer_num = local_num.clone();
 
-        let _timer = timer_subsystem.add_timer(20, Box::new(|| {
+        let _timer = timer_subsystem.add_timer(20, Box::new(move || {
             // increment up to 10 times (0 -> 9)
             // tick again in 100ms after each increment
             //
@@ -131,7 +242,7 @@ mod test {
         let local_flag = Arc::new(Mutex::new(false));
         let timer_flag = local_flag.clone();
 
-        let _timer = timer_subsystem.add_timer(20, Box::new(|| {
+        let _timer = timer_subsystem.add_timer(20, Box::new(move || {
             let mut flag = timer_flag.lock().unwrap();
             *flag = true; 0
         }));
@@ -167,4 +278,35 @@ mod test {
         let num = local_num.lock().unwrap();
         assert_eq!(*num, 2);
     }
+
+    fn test_dropped_timer_is_released() {
+        let sdl_context = crate::sdl::init().unwrap();
+        let timer_subsystem = sdl_context.timer().unwrap();
+
+        // Still scheduled when it is dropped.
+        let timer = timer_subsystem.add_timer(10_000, Box::new(|| 0));
+        let key = timer.key;
+        assert!(super::lock_timers().contains_key(&key));
+
+        drop(timer);
+        assert!(!super::lock_timers().contains_key(&key));
+    }
+
+    fn test_panicking_timer_is_cancelled() {
+        let sdl_context = crate::sdl::init().unwrap();
+        let timer_subsystem = sdl_context.timer().unwrap();
+
+        let local_num = Arc::new(Mutex::new(0));
+        let timer_num = local_num.clone();
+
+        let timer = timer_subsystem.add_timer(20, Box::new(move || {
+            *timer_num.lock().unwrap() += 1;
+            panic!("timer callback failed");
+        }));
+        let key = timer.key;
+
+        ::std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(*local_num.lock().unwrap(), 1);
+        assert!(!super::lock_timers().contains_key(&key));
+    }
 }
@@ -1,20 +1,30 @@
+//! Touch device enumeration and finger state, independent of the touch events.
+//!
+//! The touch devices are only known once the video subsystem is initialized.
 use crate::sys;
 
+/// An active finger. `x` and `y` are normalized between `0.0` and `1.0`, and so is
+/// `pressure`.
 pub type Finger = sys::SDL_Finger;
 pub type TouchDevice = sys::SDL_TouchID;
 
+/// Returns the number of registered touch devices.
 pub fn num_touch_devices() -> i32 {
     unsafe { sys::SDL_GetNumTouchDevices() }
 }
 
+/// Returns the touch device at `index`, or `0` if the index is invalid.
 pub fn touch_device(index: i32) -> TouchDevice {
     unsafe { sys::SDL_GetTouchDevice(index) }
 }
 
+/// Returns the number of fingers currently touching `touch`.
 pub fn num_touch_fingers(touch: TouchDevice) -> i32 {
     unsafe { sys::SDL_GetNumTouchFingers(touch) }
 }
 
+/// Returns the finger at `index` currently touching `touch`, or `None` if the index is
+/// invalid.
 pub fn touch_finger(touch: TouchDevice, index: i32) -> Option<Finger> {
     let raw = unsafe { sys::SDL_GetTouchFinger(touch, index) };
 
@@ -40,7 +40,34 @@ pub fn version() -> Version {
     }
 }
 
+/// Get the version of the SDL headers this crate was compiled against.
+///
+/// This is fixed at build time by the bindings in `sdl2-sys`, while
+/// `version()` reports the library that is actually loaded at runtime. The
+/// two may differ when SDL is linked dynamically; comparing them helps to
+/// diagnose ABI mismatches.
+///
+/// # Example
+///
+/// ```no_run
+/// let compiled = sdl2::version::compiled_version();
+/// let linked = sdl2::version::version();
+/// if compiled != linked {
+///     println!("compiled against SDL {}, running with SDL {}", compiled, linked);
+/// }
+/// ```
+pub fn compiled_version() -> Version {
+    Version {
+        major: sys::SDL_MAJOR_VERSION as u8,
+        minor: sys::SDL_MINOR_VERSION as u8,
+        patch: sys::SDL_PATCHLEVEL as u8,
+    }
+}
+
 /// Get the code revision of SDL that is linked against your program.
+///
+/// This is a free-form string identifying the source the library was built
+/// from, such as a Mercurial or git hash, and is mostly useful in bug reports.
 pub fn revision() -> String {
     unsafe {
         let rev = sys::SDL_GetRevision();
@@ -1,18 +1,19 @@
-use libc::{c_int, c_uint, c_float, c_char};
+use libc::{c_int, c_uint, c_float, c_char, c_void};
 use std::ffi::{CStr, CString, NulError};
 use std::{mem, ptr, fmt};
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
+use std::cell::RefCell;
 use std::error::Error;
 use std::ops::{Deref, DerefMut};
 use std::convert::TryFrom;
 
-use crate::rect::Rect;
+use crate::rect::{Point, Rect};
 use crate::render::CanvasBuilder;
 use crate::surface::SurfaceRef;
 use crate::pixels::PixelFormatEnum;
 use crate::VideoSubsystem;
 use crate::EventPump;
-use crate::common::{validate_int, IntegerOrSdlError};
+use crate::common::{missing_function_error, validate_int, IntegerOrSdlError};
 
 use crate::get_error;
 
@@ -20,6 +21,20 @@ use crate::sys;
 
 pub use crate::sys::{VkInstance, VkSurfaceKHR};
 
+mod ll {
+    use libc::c_int;
+    use crate::sys;
+
+    // Not in the SDL 2.0.10 bindings; looked up at runtime.
+    optional_sdl_fns! {
+        pub fn SDL_FlashWindow(window: *mut sys::SDL_Window, operation: c_int) -> c_int;
+        pub fn SDL_SetWindowAlwaysOnTop(window: *mut sys::SDL_Window, on_top: sys::SDL_bool) -> ();
+        pub fn SDL_SetWindowMouseRect(window: *mut sys::SDL_Window, rect: *const sys::SDL_Rect) -> c_int;
+        pub fn SDL_GetWindowMouseRect(window: *mut sys::SDL_Window) -> *const sys::SDL_Rect;
+        pub fn SDL_GetPointDisplayIndex(point: *const sys::SDL_Point) -> c_int;
+    }
+}
+
 
 pub struct WindowSurfaceRef<'a>(&'a mut SurfaceRef, &'a Window);
 
@@ -301,6 +316,9 @@ pub mod gl_attr {
     }
 
     /// **Sets** the OpenGL context major and minor versions.
+    ///
+    /// On macOS, contexts newer than OpenGL 2.1 are only available with the
+    /// `GLProfile::Core` profile, so set it with `set_context_profile` as well.
     #[inline]
     pub fn set_context_version(&self, major: u8, minor: u8) {
         self.set_context_major_version(major);
@@ -476,6 +494,95 @@ impl FullscreenType {
     }
 }
 
+/// The operation to perform with `Window::flash`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(i32)]
+pub enum FlashOperation {
+    /// Cancel any window flash state
+    Cancel = 0,
+    /// Flash the window briefly to get attention
+    Briefly = 1,
+    /// Flash the window until it gets focus
+    UntilFocused = 2,
+}
+
+impl FlashOperation {
+    /// Returns the matching `SDL_FlashOperation` value.
+    pub fn to_ll(self) -> c_int {
+        self as c_int
+    }
+}
+
+/// The orientation of a display, as returned by `VideoSubsystem::display_orientation`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(i32)]
+pub enum Orientation {
+    /// The display orientation can't be determined
+    Unknown = sys::SDL_DisplayOrientation::SDL_ORIENTATION_UNKNOWN as i32,
+    /// The display is in landscape mode, with the right side up, relative to portrait mode
+    Landscape = sys::SDL_DisplayOrientation::SDL_ORIENTATION_LANDSCAPE as i32,
+    /// The display is in landscape mode, with the left side up, relative to portrait mode
+    LandscapeFlipped = sys::SDL_DisplayOrientation::SDL_ORIENTATION_LANDSCAPE_FLIPPED as i32,
+    /// The display is in portrait mode
+    Portrait = sys::SDL_DisplayOrientation::SDL_ORIENTATION_PORTRAIT as i32,
+    /// The display is in portrait mode, upside down
+    PortraitFlipped = sys::SDL_DisplayOrientation::SDL_ORIENTATION_PORTRAIT_FLIPPED as i32,
+}
+
+impl Orientation {
+    pub fn from_ll(orientation: i32) -> Orientation {
+        use crate::sys::SDL_DisplayOrientation::*;
+        match orientation {
+            n if n == SDL_ORIENTATION_LANDSCAPE as i32 => Orientation::Landscape,
+            n if n == SDL_ORIENTATION_LANDSCAPE_FLIPPED as i32 => Orientation::LandscapeFlipped,
+            n if n == SDL_ORIENTATION_PORTRAIT as i32 => Orientation::Portrait,
+            n if n == SDL_ORIENTATION_PORTRAIT_FLIPPED as i32 => Orientation::PortraitFlipped,
+            _ => Orientation::Unknown,
+        }
+    }
+
+    pub fn to_ll(self) -> i32 {
+        self as i32
+    }
+}
+
+/// How a region of a window is treated when clicked, as returned by the
+/// callback passed to `Window::set_hit_test`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(i32)]
+pub enum HitTestResult {
+    /// The region has no special properties
+    Normal = sys::SDL_HitTestResult::SDL_HITTEST_NORMAL as i32,
+    /// The region can be used to drag the whole window
+    Draggable = sys::SDL_HitTestResult::SDL_HITTEST_DRAGGABLE as i32,
+    ResizeTopLeft = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_TOPLEFT as i32,
+    ResizeTop = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_TOP as i32,
+    ResizeTopRight = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_TOPRIGHT as i32,
+    ResizeRight = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_RIGHT as i32,
+    ResizeBottomRight = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_BOTTOMRIGHT as i32,
+    ResizeBottom = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_BOTTOM as i32,
+    ResizeBottomLeft = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_BOTTOMLEFT as i32,
+    ResizeLeft = sys::SDL_HitTestResult::SDL_HITTEST_RESIZE_LEFT as i32,
+}
+
+impl HitTestResult {
+    pub fn to_ll(self) -> sys::SDL_HitTestResult {
+        use crate::sys::SDL_HitTestResult::*;
+        match self {
+            HitTestResult::Normal => SDL_HITTEST_NORMAL,
+            HitTestResult::Draggable => SDL_HITTEST_DRAGGABLE,
+            HitTestResult::ResizeTopLeft => SDL_HITTEST_RESIZE_TOPLEFT,
+            HitTestResult::ResizeTop => SDL_HITTEST_RESIZE_TOP,
+            HitTestResult::ResizeTopRight => SDL_HITTEST_RESIZE_TOPRIGHT,
+            HitTestResult::ResizeRight => SDL_HITTEST_RESIZE_RIGHT,
+            HitTestResult::ResizeBottomRight => SDL_HITTEST_RESIZE_BOTTOMRIGHT,
+            HitTestResult::ResizeBottom => SDL_HITTEST_RESIZE_BOTTOM,
+            HitTestResult::ResizeBottomLeft => SDL_HITTEST_RESIZE_BOTTOMLEFT,
+            HitTestResult::ResizeLeft => SDL_HITTEST_RESIZE_LEFT,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum WindowPos {
     Undefined,
@@ -517,12 +624,41 @@ impl GLContext {
 pub struct WindowContext {
     subsystem: VideoSubsystem,
     raw: *mut sys::SDL_Window,
+    hit_test: RefCell<Option<Box<HitTestState>>>,
+}
+
+type HitTestCallback = Box<dyn FnMut(&Window, Point) -> HitTestResult>;
+
+struct HitTestState {
+    context: Weak<WindowContext>,
+    callback: HitTestCallback,
+}
+
+/// The name under which a `Weak<WindowContext>` is attached to every window
+/// wrapped in a `Window`, so that `VideoSubsystem::window_from_id` can find it.
+const WINDOW_CONTEXT_DATA: &[u8] = b"rust-sdl2.window_context\0";
+
+const HIT_TEST_RUNNING_ERROR: &str = "the hit test callback can't be changed while it is running";
+
+/// Attaches `weak` to `raw`, dropping any previously attached context.
+unsafe fn replace_window_context_data(raw: *mut sys::SDL_Window, weak: Option<Weak<WindowContext>>) {
+    let data = match weak {
+        Some(weak) => Box::into_raw(Box::new(weak)) as *mut c_void,
+        None => ptr::null_mut(),
+    };
+    let old = sys::SDL_SetWindowData(raw, WINDOW_CONTEXT_DATA.as_ptr() as *const c_char, data);
+    if !old.is_null() {
+        drop(Box::from_raw(old as *mut Weak<WindowContext>));
+    }
 }
 
 impl Drop for WindowContext {
     #[inline]
     fn drop(&mut self) {
-        unsafe { sys::SDL_DestroyWindow(self.raw) };
+        unsafe {
+            replace_window_context_data(self.raw, None);
+            sys::SDL_DestroyWindow(self.raw)
+        };
     }
 }
 
@@ -533,6 +669,7 @@ impl WindowContext {
         WindowContext {
             subsystem: subsystem.clone(),
             raw,
+            hit_test: RefCell::new(None),
         }
     }
 }
@@ -569,11 +706,15 @@ impl From<i32> for SwapInterval {
 /// This may happen when a `TextureCreator<Window>` outlives the `Canvas<Window>`
 pub struct Window {
     context: Rc<WindowContext>,
+    /// Position and size of the window before `toggle_fullscreen_desktop` went fullscreen
+    windowed_rect: Option<Rect>,
 }
 
 impl From<WindowContext> for Window {
     fn from(context: WindowContext) -> Window {
-        Window { context: Rc::new(context) }
+        let context = Rc::new(context);
+        unsafe { replace_window_context_data(context.raw, Some(Rc::downgrade(&context))) };
+        Window { context, windowed_rect: None }
     }
 }
 
@@ -587,6 +728,29 @@ impl VideoSubsystem {
         WindowBuilder::new(self, title, width, height)
     }
 
+    /// Returns the window with the given `id`, as found in the `window_id` of
+    /// window, keyboard and mouse events.
+    ///
+    /// The returned `Window` shares its context with the original one, so the
+    /// window stays alive until both are dropped. Returns `None` if there is
+    /// no such window, or if it wasn't created through this crate.
+    pub fn window_from_id(&self, id: u32) -> Option<Window> {
+        unsafe {
+            let raw = sys::SDL_GetWindowFromID(id);
+            if raw.is_null() {
+                return None;
+            }
+
+            let data = sys::SDL_GetWindowData(raw, WINDOW_CONTEXT_DATA.as_ptr() as *const c_char);
+            if data.is_null() {
+                return None;
+            }
+
+            let weak = &*(data as *const Weak<WindowContext>);
+            weak.upgrade().map(|context| Window { context, windowed_rect: None })
+        }
+    }
+
     pub fn current_video_driver(&self) -> &'static str {
         use std::str;
 
@@ -622,6 +786,10 @@ impl VideoSubsystem {
         }
     }
 
+    /// Returns the desktop area represented by the display at `display_index`,
+    /// with the primary display located at `(0, 0)`.
+    ///
+    /// Returns an error if the index is out of bounds.
     pub fn display_bounds(&self, display_index: i32) -> Result<Rect, String> {
         let mut out = mem::MaybeUninit::uninit();
         let result = unsafe { sys::SDL_GetDisplayBounds(display_index as c_int, out.as_mut_ptr()) == 0 };
@@ -634,6 +802,59 @@ impl VideoSubsystem {
         }
     }
 
+    /// Returns the usable desktop area of the display at `display_index`.
+    ///
+    /// This is the same area as `display_bounds`, minus any space reserved by
+    /// the OS, such as a taskbar or the menu bar and dock on macOS.
+    ///
+    /// Returns an error if the index is out of bounds.
+    pub fn display_usable_bounds(&self, display_index: i32) -> Result<Rect, String> {
+        let mut out = mem::MaybeUninit::uninit();
+        let result = unsafe { sys::SDL_GetDisplayUsableBounds(display_index as c_int, out.as_mut_ptr()) == 0 };
+
+        if result {
+            let out = unsafe { out.assume_init() };
+            Ok(Rect::from_ll(out))
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Returns the index of the display containing `point`, in desktop
+    /// coordinates, or `None` if the point isn't on any display.
+    ///
+    /// This uses `SDL_GetPointDisplayIndex` when the linked SDL provides it
+    /// (SDL 2.24.0 or later), and otherwise checks the point against the
+    /// `display_bounds` of every display, so it works on any SDL version.
+    pub fn point_display_index<P: Into<Point>>(&self, point: P) -> Option<i32> {
+        let point = point.into();
+        let raw = sys::SDL_Point { x: point.x(), y: point.y() };
+        match unsafe { ll::SDL_GetPointDisplayIndex(&raw) } {
+            Some(index) if index >= 0 => return Some(index as i32),
+            Some(_) => return None,
+            None => {}
+        }
+
+        let num_displays = self.num_video_displays().ok()?;
+
+        (0..num_displays).find(|&index| {
+            self.display_bounds(index)
+                .map(|bounds| bounds.contains_point(point))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Returns the orientation of the display at `display_index`.
+    ///
+    /// Returns `Orientation::Unknown` if the index is out of bounds or if the
+    /// orientation isn't available on the current platform. Changes are
+    /// reported by `DisplayEvent::OrientationChanged` events.
+    pub fn display_orientation(&self, display_index: i32) -> Orientation {
+        let orientation = unsafe { sys::SDL_GetDisplayOrientation(display_index as c_int) };
+        Orientation::from_ll(orientation as i32)
+    }
+
+    /// Returns the number of fullscreen display modes available on the display.
     pub fn num_display_modes(&self, display_index: i32) -> Result<i32, String> {
         let result = unsafe { sys::SDL_GetNumDisplayModes(display_index as c_int) };
         if result < 0 {
@@ -643,6 +864,11 @@ impl VideoSubsystem {
         }
     }
 
+    /// Returns the fullscreen display mode at `mode_index`, between `0` and
+    /// `num_display_modes(display_index)`.
+    ///
+    /// Modes are sorted by width, height, bits per pixel, and refresh rate, from largest to
+    /// smallest.
     pub fn display_mode(&self, display_index: i32, mode_index: i32) -> Result<DisplayMode, String> {
         let mut dm = mem::MaybeUninit::uninit();
         let result = unsafe { sys::SDL_GetDisplayMode(display_index as c_int, mode_index as c_int, dm.as_mut_ptr()) == 0};
@@ -679,6 +905,10 @@ impl VideoSubsystem {
         }
     }
 
+    /// Returns the available display mode closest to `mode`.
+    ///
+    /// A `format` of `PixelFormatEnum::Unknown` or a `refresh_rate` of `0` in `mode` match the
+    /// desktop mode's. Returns an error if no mode is at least as large as `mode`.
     pub fn closest_display_mode(&self, display_index: i32, mode: &DisplayMode) -> Result<DisplayMode, String> {
         let input = mode.to_ll();
         let mut dm = mem::MaybeUninit::uninit();
@@ -695,6 +925,8 @@ impl VideoSubsystem {
 
     /// Return a triplet `(ddpi, hdpi, vdpi)` containing the diagonal, horizontal and vertical
     /// dots/pixels-per-inch of a display
+    ///
+    /// Returns an error if the DPI of the display isn't available on the current platform.
     pub fn display_dpi(&self, display_index: i32) -> Result<(f32, f32, f32), String> {
         let mut ddpi = 0.0;
         let mut hdpi = 0.0;
@@ -1059,6 +1291,12 @@ impl WindowBuilder {
         self.window_flags |= sys::SDL_WindowFlags::SDL_WINDOW_ALLOW_HIGHDPI as u32;
         self
     }
+
+    /// Keeps the window above all other windows (older SDL versions only support it on X11)
+    pub fn always_on_top(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as u32;
+        self
+    }
 }
 
 impl From<Window> for CanvasBuilder {
@@ -1080,7 +1318,7 @@ impl Window {
     #[inline]
     /// Create a new `Window` without taking ownership of the `WindowContext`
     pub const unsafe fn from_ref(context: Rc<WindowContext>) -> Window {
-        Window { context }
+        Window { context, windowed_rect: None }
     }
 
     #[inline]
@@ -1095,10 +1333,19 @@ impl Window {
         self.context.clone()
     }
 
+    /// Returns the numeric id of the window, as used in events and by
+    /// `VideoSubsystem::window_from_id`.
     pub fn id(&self) -> u32 {
         unsafe { sys::SDL_GetWindowID(self.context.raw) }
     }
 
+    /// Creates an OpenGL context for use with this window, and makes it
+    /// current.
+    ///
+    /// To create several contexts that share textures, buffers and other
+    /// objects, call `GLAttr::set_share_with_current_context(true)` before
+    /// creating the additional contexts. The context is deleted when the
+    /// returned `GLContext` is dropped.
     pub fn gl_create_context(&self) -> Result<GLContext, String> {
         let result = unsafe { sys::SDL_GL_CreateContext(self.context.raw) };
         if result.is_null() {
@@ -1121,6 +1368,8 @@ impl Window {
         }
     }
 
+    /// Makes `context` the current OpenGL context on the calling thread, for
+    /// rendering into this window.
     pub fn gl_make_current(&self, context: &GLContext) -> Result<(), String> {
         unsafe {
             if sys::SDL_GL_MakeCurrent(self.context.raw, context.raw) == 0 {
@@ -1162,6 +1411,10 @@ impl Window {
         }
     }
 
+    /// Returns the index of the display containing the center of the window.
+    ///
+    /// Compare it against a previously stored value after a
+    /// `WindowEvent::Moved` to notice when the window changes displays.
     pub fn display_index(&self) -> Result<i32, String> {
         let result = unsafe { sys::SDL_GetWindowDisplayIndex(self.context.raw) };
         if result < 0 {
@@ -1171,6 +1424,9 @@ impl Window {
         }
     }
 
+    /// Sets the display mode used when the window is fullscreen (but not desktop fullscreen).
+    ///
+    /// Passing `None` uses the window's size and the desktop's format and refresh rate.
     pub fn set_display_mode<D>(&mut self, display_mode: D) -> Result<(), String>
     where D: Into<Option<DisplayMode>>
     {
@@ -1190,6 +1446,7 @@ impl Window {
         }
     }
 
+    /// Returns the display mode used when the window is fullscreen.
     pub fn display_mode(&self) -> Result<DisplayMode, String> {
         let mut dm = mem::MaybeUninit::uninit();
 
@@ -1237,6 +1494,10 @@ impl Window {
 
     /// Use this function to set the icon for a window.
     ///
+    /// SDL copies the pixels of the icon, so the surface can be dropped right
+    /// after this call. 32-bit surfaces with an alpha channel, such as
+    /// `PixelFormatEnum::RGBA32`, give the best results.
+    ///
     /// # Example:
     /// ```compile_fail
     /// // requires "--features 'image'"
@@ -1310,6 +1571,10 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Returns the size of the window's underlying drawable in pixels, for use
+    /// as the extent of a Vulkan swapchain.
+    ///
+    /// This may differ from `size()` on HiDPI displays.
     pub fn vulkan_drawable_size(&self) -> (u32, u32) {
         let mut w: c_int = 0;
         let mut h: c_int = 0;
@@ -1317,6 +1582,8 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Sets the minimum size of the window's client area, which SDL enforces
+    /// when the user resizes the window.
     pub fn set_minimum_size(&mut self, width: u32, height: u32)
             -> Result<(), IntegerOrSdlError> {
         let w = validate_int(width, "width")?;
@@ -1327,6 +1594,7 @@ impl Window {
         Ok(())
     }
 
+    /// Returns the minimum size of the window's client area.
     pub fn minimum_size(&self) -> (u32, u32) {
         let mut w: c_int = 0;
         let mut h: c_int = 0;
@@ -1334,6 +1602,8 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Sets the maximum size of the window's client area, which SDL enforces
+    /// when the user resizes the window.
     pub fn set_maximum_size(&mut self, width: u32, height: u32)
             -> Result<(), IntegerOrSdlError> {
         let w = validate_int(width, "width")?;
@@ -1344,6 +1614,7 @@ impl Window {
         Ok(())
     }
 
+    /// Returns the maximum size of the window's client area.
     pub fn maximum_size(&self) -> (u32, u32) {
         let mut w: c_int = 0;
         let mut h: c_int = 0;
@@ -1370,18 +1641,25 @@ impl Window {
         unsafe { sys::SDL_HideWindow(self.context.raw) }
     }
 
+    /// Raises the window above other windows and sets the input focus.
     pub fn raise(&mut self) {
         unsafe { sys::SDL_RaiseWindow(self.context.raw) }
     }
 
+    /// Makes the window as large as possible.
     pub fn maximize(&mut self) {
         unsafe { sys::SDL_MaximizeWindow(self.context.raw) }
     }
 
+    /// Minimizes the window to an iconic representation.
     pub fn minimize(&mut self) {
         unsafe { sys::SDL_MinimizeWindow(self.context.raw) }
     }
 
+    /// Restores the size and position of a minimized or maximized window.
+    ///
+    /// This doesn't give the window the input focus; call `raise` afterwards
+    /// to bring it to the front.
     pub fn restore(&mut self) {
         unsafe { sys::SDL_RestoreWindow(self.context.raw) }
     }
@@ -1404,6 +1682,34 @@ impl Window {
         }
     }
 
+    /// Switches the window between windowed mode and `FullscreenType::Desktop`.
+    ///
+    /// The position and size of the window are saved when going fullscreen, and restored
+    /// when going back to windowed mode. If the window is in `FullscreenType::True`,
+    /// it goes back to windowed mode.
+    ///
+    /// The saved geometry lives on this `Window`, other `Window`s sharing the same
+    /// `WindowContext` don't see it.
+    pub fn toggle_fullscreen_desktop(&mut self) -> Result<(), String> {
+        match self.fullscreen_state() {
+            FullscreenType::Off => {
+                let (x, y) = self.position();
+                let (w, h) = self.size();
+                self.set_fullscreen(FullscreenType::Desktop)?;
+                self.windowed_rect = Some(Rect::new(x, y, w, h));
+            },
+            FullscreenType::True | FullscreenType::Desktop => {
+                self.set_fullscreen(FullscreenType::Off)?;
+                if let Some(rect) = self.windowed_rect.take() {
+                    self.set_size(rect.width(), rect.height()).map_err(|e| e.to_string())?;
+                    self.set_position(WindowPos::Positioned(rect.x()),
+                                      WindowPos::Positioned(rect.y()));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns a WindowSurfaceRef, which can be used like a regular Surface. This is an
     /// alternative way to the Renderer (Canvas) way to modify pixels directly in the Window.
     ///
@@ -1427,14 +1733,70 @@ impl Window {
         }
     }
 
+    /// Sets whether the window is kept above all other windows.
+    ///
+    /// Returns an error if the linked SDL is older than 2.0.16 or statically linked; use
+    /// `WindowBuilder::always_on_top` to set this then.
+    pub fn set_always_on_top(&mut self, on_top: bool) -> Result<(), String> {
+        let on_top = if on_top { sys::SDL_bool::SDL_TRUE } else { sys::SDL_bool::SDL_FALSE };
+
+        match unsafe { ll::SDL_SetWindowAlwaysOnTop(self.context.raw, on_top) } {
+            Some(()) => Ok(()),
+            None => Err(missing_function_error("SDL_SetWindowAlwaysOnTop", "2.0.16")),
+        }
+    }
+
+    /// Returns whether the window is kept above all other windows.
+    pub fn is_always_on_top(&self) -> bool {
+        self.window_flags() & sys::SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as u32 != 0
+    }
+
+    /// Grabs the input, confining the mouse to the window.
+    ///
+    /// This only grabs the keyboard too if the `SDL_GRAB_KEYBOARD` hint is set. Grabbing the
+    /// keyboard sends system shortcuts such as Alt+Tab to the window instead of the system.
     pub fn set_grab(&mut self, grabbed: bool) {
         unsafe { sys::SDL_SetWindowGrab(self.context.raw, if grabbed { sys::SDL_bool::SDL_TRUE } else { sys::SDL_bool::SDL_FALSE }) }
     }
 
+    /// Returns whether the input is grabbed by the window.
     pub fn grab(&self) -> bool {
         unsafe { sys::SDL_GetWindowGrab(self.context.raw) == sys::SDL_bool::SDL_TRUE }
     }
 
+    /// Confines the cursor to `rect`, in window coordinates, while the window has mouse focus.
+    ///
+    /// This doesn't grab the mouse. Passing `None` removes the confinement.
+    ///
+    /// Returns an error if the linked SDL is older than 2.0.18 or statically linked.
+    pub fn set_mouse_rect<R>(&mut self, rect: R) -> Result<(), String>
+        where R: Into<Option<Rect>>
+    {
+        let rect = rect.into();
+        let rect_raw_ptr = match rect {
+            Some(ref rect) => rect.raw(),
+            None => ptr::null(),
+        };
+        match unsafe { ll::SDL_SetWindowMouseRect(self.context.raw, rect_raw_ptr) } {
+            Some(0) => Ok(()),
+            Some(_) => Err(get_error()),
+            None => Err(missing_function_error("SDL_SetWindowMouseRect", "2.0.18")),
+        }
+    }
+
+    /// Returns the rectangle the cursor is confined to, if any.
+    ///
+    /// Always returns `None` if the linked SDL is older than 2.0.18 or statically linked, as
+    /// `set_mouse_rect` can't confine the cursor then.
+    pub fn mouse_rect(&self) -> Option<Rect> {
+        let raw = unsafe { ll::SDL_GetWindowMouseRect(self.context.raw) }.unwrap_or(ptr::null());
+        if raw.is_null() {
+            None
+        } else {
+            Some(Rect::from_ll(unsafe { *raw }))
+        }
+    }
+
     pub fn set_brightness(&mut self, brightness: f64) -> Result<(), String> {
         unsafe {
             if sys::SDL_SetWindowBrightness(self.context.raw, brightness as c_float) == 0 {
@@ -1496,11 +1858,12 @@ impl Window {
         }
     }
 
-    /// Set the transparency of the window. The given value will be clamped internally between
-    /// `0.0` (fully transparent), and `1.0` (fully opaque).
+    /// Set the transparency of the window. The given value will be clamped between
+    /// `0.0` (fully transparent), and `1.0` (fully opaque). NaN is treated as `0.0`.
     ///
     /// This method returns an error if opacity isn't supported by the current platform.
     pub fn set_opacity(&mut self, opacity: f32) -> Result<(), String> {
+        let opacity = opacity.max(0.0).min(1.0);
         let result = unsafe { sys::SDL_SetWindowOpacity(self.context.raw, opacity) };
         if result < 0 {
             Err(get_error())
@@ -1523,6 +1886,110 @@ impl Window {
             Ok(opacity)
         }
     }
+
+    /// Sets a callback that decides how clicks on each region of the window are
+    /// treated, e.g. to make a custom-drawn title bar of a borderless window
+    /// draggable.
+    ///
+    /// The callback receives the window and the clicked point, in window
+    /// coordinates. It is kept until it is replaced, removed with
+    /// `remove_hit_test`, or the window is destroyed. If the callback drops the
+    /// last `Window` for this window, the window is destroyed the next time the
+    /// `EventPump` is used rather than from inside the callback.
+    ///
+    /// This method returns an error if hit testing isn't supported by the current platform, or if
+    /// it is called from inside this window's hit test callback.
+    pub fn set_hit_test<F>(&mut self, callback: F) -> Result<(), String>
+        where F: FnMut(&Window, Point) -> HitTestResult + 'static
+    {
+        // The trampoline keeps this borrowed while the callback runs, so the state it is using
+        // can't be replaced from inside the callback.
+        let mut hit_test = self.context.hit_test.try_borrow_mut()
+            .map_err(|_| HIT_TEST_RUNNING_ERROR.to_owned())?;
+        let mut state = Box::new(HitTestState {
+            context: Rc::downgrade(&self.context),
+            callback: Box::new(callback),
+        });
+        let result = unsafe {
+            sys::SDL_SetWindowHitTest(
+                self.context.raw,
+                Some(hit_test_trampoline),
+                &mut *state as *mut HitTestState as *mut c_void
+            )
+        };
+
+        if result == 0 {
+            *hit_test = Some(state);
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Removes the callback set with `set_hit_test`, if any.
+    ///
+    /// This method returns an error if it is called from inside this window's hit test callback.
+    pub fn remove_hit_test(&mut self) -> Result<(), String> {
+        let mut hit_test = self.context.hit_test.try_borrow_mut()
+            .map_err(|_| HIT_TEST_RUNNING_ERROR.to_owned())?;
+        unsafe { sys::SDL_SetWindowHitTest(self.context.raw, None, ptr::null_mut()) };
+        hit_test.take();
+        Ok(())
+    }
+
+    /// Requests the window to demand attention from the user, e.g. by flashing its taskbar entry.
+    ///
+    /// This method returns an error if flashing isn't supported by the current platform, or if
+    /// the linked SDL is older than 2.0.16 or statically linked.
+    pub fn flash(&mut self, operation: FlashOperation) -> Result<(), String> {
+        match unsafe { ll::SDL_FlashWindow(self.context.raw, operation.to_ll()) } {
+            Some(0) => Ok(()),
+            Some(_) => Err(get_error()),
+            None => Err(missing_function_error("SDL_FlashWindow", "2.0.16")),
+        }
+    }
+}
+
+unsafe extern "C" fn hit_test_trampoline(
+    _window: *mut sys::SDL_Window,
+    area: *const sys::SDL_Point,
+    data: *mut c_void,
+) -> sys::SDL_HitTestResult {
+    let context = match (*(data as *const HitTestState)).context.upgrade() {
+        Some(context) => context,
+        None => return sys::SDL_HitTestResult::SDL_HITTEST_NORMAL,
+    };
+    let window = Window { context, windowed_rect: None };
+
+    // Hold the borrow for the whole call: `set_hit_test` and `remove_hit_test` refuse to run
+    // while it is taken, so the callback can't free the state it is running from.
+    let result = match window.context.hit_test.try_borrow_mut() {
+        Ok(mut hit_test) => match *hit_test {
+            Some(ref mut state) => (state.callback)(&window, Point::from_ll(*area)).to_ll(),
+            None => sys::SDL_HitTestResult::SDL_HITTEST_NORMAL,
+        },
+        Err(_) => sys::SDL_HitTestResult::SDL_HITTEST_NORMAL,
+    };
+
+    // If the callback dropped every other `Window` for this window, dropping ours would run
+    // `SDL_DestroyWindow` from inside SDL's own callback. Keep it until events are next pumped.
+    if Rc::strong_count(&window.context) == 1 {
+        DEFERRED_WINDOW_CONTEXTS.with(|deferred| deferred.borrow_mut().push(window.context));
+    }
+    result
+}
+
+thread_local! {
+    // Windows whose last `Window` was dropped inside a hit test callback.
+    static DEFERRED_WINDOW_CONTEXTS: RefCell<Vec<Rc<WindowContext>>> = RefCell::new(Vec::new());
+}
+
+/// Destroys the windows whose last `Window` was dropped inside a hit test callback.
+///
+/// Called by the `EventPump` before it pumps events, outside of any SDL callback.
+pub(crate) fn release_deferred_windows() {
+    let deferred = DEFERRED_WINDOW_CONTEXTS.with(|deferred| mem::replace(&mut *deferred.borrow_mut(), Vec::new()));
+    drop(deferred);
 }
 
 #[derive(Copy, Clone)]
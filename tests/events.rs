@@ -13,6 +13,9 @@ fn test_events() {
 
     test3(&ev);
     test4(&ev, &mut ep);
+
+    test_event_filter(&ev, &mut ep);
+    test_replaced_event_filter(&ev, &mut ep);
 }
 
 fn test1(ev: &sdl2::EventSubsystem) {
@@ -120,4 +123,59 @@ fn test_event_sender_no_subsystem() {
         window_id: 0,
         win_event: sdl2::event::WindowEvent::Hidden,
     }).is_err());
-}
\ No newline at end of file
+}
+
+fn push_user_event(ev: &sdl2::EventSubsystem, type_: u32, code: i32) {
+    // Pushing an event that a filter drops returns an error, so the result is ignored.
+    let _ = ev.push_event(event::Event::User {
+        timestamp: 0,
+        window_id: 0,
+        type_,
+        code,
+        data1: ::std::ptr::null_mut(),
+        data2: ::std::ptr::null_mut(),
+    });
+}
+
+fn received_user_codes(ep: &mut sdl2::EventPump, type_: u32) -> Vec<i32> {
+    ep.poll_iter().filter_map(|event| match event {
+        event::Event::User { type_: t, code, .. } if t == type_ => Some(code),
+        _ => None,
+    }).collect()
+}
+
+fn test_event_filter(ev: &sdl2::EventSubsystem, ep: &mut sdl2::EventPump) {
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+
+    let filter = ev.set_event_filter(move |event| match *event {
+        event::Event::User { type_, code, .. } => type_ != user_event_id || code != 1,
+        _ => true,
+    });
+    push_user_event(ev, user_event_id, 1);
+    push_user_event(ev, user_event_id, 2);
+    assert_eq!(received_user_codes(ep, user_event_id), vec![2]);
+
+    drop(filter);
+    push_user_event(ev, user_event_id, 1);
+    assert_eq!(received_user_codes(ep, user_event_id), vec![1]);
+}
+
+fn test_replaced_event_filter(ev: &sdl2::EventSubsystem, ep: &mut sdl2::EventPump) {
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+
+    let old_filter = ev.set_event_filter(|_| true);
+    let new_filter = ev.set_event_filter(move |event| match *event {
+        event::Event::User { type_, code, .. } => type_ != user_event_id || code != 1,
+        _ => true,
+    });
+
+    // Dropping the replaced filter's guard must leave the new filter in place.
+    drop(old_filter);
+    push_user_event(ev, user_event_id, 1);
+    push_user_event(ev, user_event_id, 2);
+    assert_eq!(received_user_codes(ep, user_event_id), vec![2]);
+
+    drop(new_filter);
+    push_user_event(ev, user_event_id, 1);
+    assert_eq!(received_user_codes(ep, user_event_id), vec![1]);
+}